@@ -0,0 +1,234 @@
+use crate::{Capabilities, InitializeRequestArguments};
+
+/// Returns whether `OutputEvent.output` and `Variable.value` may contain ANSI escape sequences
+/// that should be interpreted. This is only the case if both the client and the debug adapter
+/// announced `supportsANSIStyling`; otherwise the text has to be shown literally.
+pub fn styling_enabled(client: &InitializeRequestArguments, adapter: &Capabilities) -> bool {
+    client.supports_ansistyling.unwrap_or(false) && adapter.supports_ansistyling.unwrap_or(false)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// A color from the 256 color palette. Indices 0-7 are the standard colors and 8-15 their
+    /// bright variants.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+/// A run of text with a single style.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: Style,
+}
+
+/// Splits text into styled spans according to the SGR (select graphic rendition) sequences it
+/// contains. Other escape sequences are removed.
+pub fn parse(text: &str) -> Vec<StyledSpan> {
+    let mut spans = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    for token in tokenize(text) {
+        match token {
+            Token::Text(text) => current.push_str(text),
+            Token::Sgr(params) => {
+                let mut next = style;
+                apply_sgr(&mut next, params);
+                if next != style && !current.is_empty() {
+                    spans.push(StyledSpan {
+                        text: std::mem::take(&mut current),
+                        style,
+                    });
+                }
+                style = next;
+            }
+            Token::Other => {}
+        }
+    }
+    if !current.is_empty() {
+        spans.push(StyledSpan {
+            text: current,
+            style,
+        });
+    }
+    spans
+}
+
+/// Like [`parse`], but returns the text unstyled as a single span when styling has not been
+/// negotiated (see [`styling_enabled`]).
+pub fn parse_if_enabled(text: &str, enabled: bool) -> Vec<StyledSpan> {
+    if enabled {
+        parse(text)
+    } else if text.is_empty() {
+        Vec::new()
+    } else {
+        vec![StyledSpan {
+            text: text.to_owned(),
+            style: Style::default(),
+        }]
+    }
+}
+
+/// Removes all escape sequences from the text, for clients that cannot render styles.
+pub fn strip(text: &str) -> String {
+    tokenize(text)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            Token::Sgr(_) | Token::Other => None,
+        })
+        .collect()
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    /// Parameters of a `CSI ... m` sequence.
+    Sgr(&'a str),
+    /// Any other escape sequence.
+    Other,
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = Token<'_>> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let Some(escape) = rest.strip_prefix('\x1b') else {
+            let end = rest.find('\x1b').unwrap_or(rest.len());
+            let (text, tail) = rest.split_at(end);
+            rest = tail;
+            return Some(Token::Text(text));
+        };
+        if let Some(csi) = escape.strip_prefix('[') {
+            // Control sequence: parameter and intermediate bytes, then a final byte.
+            match csi.find(|c: char| ('\x40'..='\x7e').contains(&c)) {
+                Some(end) => {
+                    rest = &csi[end + 1..];
+                    if csi.as_bytes()[end] == b'm' {
+                        return Some(Token::Sgr(&csi[..end]));
+                    }
+                }
+                None => rest = "",
+            }
+        } else if let Some(osc) = escape.strip_prefix(']') {
+            // Operating system command, terminated by BEL or ST (`ESC \`).
+            rest = match (osc.find('\x07'), osc.find("\x1b\\")) {
+                (Some(bel), Some(st)) if st < bel => &osc[st + 2..],
+                (Some(bel), _) => &osc[bel + 1..],
+                (None, Some(st)) => &osc[st + 2..],
+                (None, None) => "",
+            };
+        } else {
+            // Two byte escape sequence.
+            let mut chars = escape.chars();
+            chars.next();
+            rest = chars.as_str();
+        }
+        Some(Token::Other)
+    })
+}
+
+fn apply_sgr(style: &mut Style, params: &str) {
+    let mut params = params
+        .split([';', ':'])
+        .map(|p| p.parse::<u32>().unwrap_or(0));
+    // `ESC[m` is equivalent to `ESC[0m`.
+    let mut next = params.next();
+    while let Some(code) = next {
+        match code {
+            0 => *style = Style::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            22 => {
+                style.bold = false;
+                style.dim = false;
+            }
+            23 => style.italic = false,
+            24 => style.underline = false,
+            30..=37 => style.foreground = Some(Color::Indexed((code - 30) as u8)),
+            38 => style.foreground = extended_color(&mut params),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(Color::Indexed((code - 40) as u8)),
+            48 => style.background = extended_color(&mut params),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(Color::Indexed((code - 90 + 8) as u8)),
+            100..=107 => style.background = Some(Color::Indexed((code - 100 + 8) as u8)),
+            _ => {}
+        }
+        next = params.next();
+    }
+}
+
+/// Parses the arguments of an extended color (`38;5;n` or `38;2;r;g;b`).
+fn extended_color(params: &mut impl Iterator<Item = u32>) -> Option<Color> {
+    let channel = |value: Option<u32>| value.map_or(0, |v| v.min(255) as u8);
+    match params.next() {
+        Some(5) => Some(Color::Indexed(channel(params.next()))),
+        Some(2) => Some(Color::Rgb(
+            channel(params.next()),
+            channel(params.next()),
+            channel(params.next()),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sgr_sequences() {
+        let spans = parse("plain \x1b[1;31mbold red\x1b[0m \x1b[38;2;1;2;3;4munder\x1b[24;39m\x1b]0;title\x07done\x1b[");
+        assert_eq!(
+            spans,
+            vec![
+                StyledSpan {
+                    text: "plain ".to_owned(),
+                    style: Style::default(),
+                },
+                StyledSpan {
+                    text: "bold red".to_owned(),
+                    style: Style {
+                        foreground: Some(Color::Indexed(1)),
+                        bold: true,
+                        ..Style::default()
+                    },
+                },
+                StyledSpan {
+                    text: " ".to_owned(),
+                    style: Style::default(),
+                },
+                StyledSpan {
+                    text: "under".to_owned(),
+                    style: Style {
+                        foreground: Some(Color::Rgb(1, 2, 3)),
+                        underline: true,
+                        ..Style::default()
+                    },
+                },
+                StyledSpan {
+                    text: "done".to_owned(),
+                    style: Style::default(),
+                },
+            ]
+        );
+        assert_eq!(
+            strip("\x1b[38;5;208morange\x1b[m text"),
+            "orange text".to_owned()
+        );
+        assert_eq!(parse_if_enabled("\x1b[1mx", false)[0].text, "\x1b[1mx");
+    }
+}
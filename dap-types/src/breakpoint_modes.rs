@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::{BreakpointMode, BreakpointModeApplicability, Capabilities, CapabilitiesEvent};
+
+/// Returns whether `mode` applies to breakpoints of kind `kind`.
+pub fn applies_to(mode: &BreakpointMode, kind: &BreakpointModeApplicability) -> bool {
+    mode.applies_to.contains(kind)
+}
+
+/// The `breakpointModes` of the adapter that apply to breakpoints of kind `kind`.
+pub fn modes_for<'a>(
+    capabilities: &'a Capabilities,
+    kind: &BreakpointModeApplicability,
+) -> Vec<&'a BreakpointMode> {
+    applicable_modes(advertised_modes(capabilities), kind)
+}
+
+/// Checks that `mode` is one of the adapter's `breakpointModes` applicable to `kind`.
+pub fn validate_mode(
+    capabilities: &Capabilities,
+    kind: &BreakpointModeApplicability,
+    mode: &str,
+) -> Result<(), UnknownModeError> {
+    validate_in(advertised_modes(capabilities), kind, mode)
+}
+
+fn advertised_modes(capabilities: &Capabilities) -> &[BreakpointMode] {
+    capabilities.breakpoint_modes.as_deref().unwrap_or_default()
+}
+
+fn applicable_modes<'a>(
+    modes: &'a [BreakpointMode],
+    kind: &BreakpointModeApplicability,
+) -> Vec<&'a BreakpointMode> {
+    modes.iter().filter(|mode| applies_to(mode, kind)).collect()
+}
+
+fn validate_in(
+    modes: &[BreakpointMode],
+    kind: &BreakpointModeApplicability,
+    mode: &str,
+) -> Result<(), UnknownModeError> {
+    if applicable_modes(modes, kind).iter().any(|m| m.mode == mode) {
+        Ok(())
+    } else {
+        Err(UnknownModeError {
+            mode: mode.to_owned(),
+            kind: kind.clone(),
+        })
+    }
+}
+
+/// Error returned when a breakpoint mode is not advertised for a kind of breakpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownModeError {
+    pub mode: String,
+    pub kind: BreakpointModeApplicability,
+}
+
+impl fmt::Display for UnknownModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} breakpoint mode `{}`", self.kind, self.mode)
+    }
+}
+
+impl std::error::Error for UnknownModeError {}
+
+/// The breakpoint modes advertised by the adapter, kept stable across `capabilities` events.
+///
+/// A `capabilities` event that does not mention `breakpointModes` leaves the modes unchanged.
+/// One that does replaces them, but modes that are still advertised keep their position, so
+/// that a list of choices in the UI does not reorder under the user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BreakpointModes {
+    modes: Vec<BreakpointMode>,
+}
+
+impl BreakpointModes {
+    pub fn new(capabilities: &Capabilities) -> Self {
+        Self {
+            modes: capabilities.breakpoint_modes.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn all(&self) -> &[BreakpointMode] {
+        &self.modes
+    }
+
+    /// The modes applicable to breakpoints of kind `kind`, e.g. to offer a choice between
+    /// hardware and software breakpoints.
+    pub fn modes_for(&self, kind: &BreakpointModeApplicability) -> Vec<&BreakpointMode> {
+        applicable_modes(&self.modes, kind)
+    }
+
+    pub fn validate(
+        &self,
+        kind: &BreakpointModeApplicability,
+        mode: &str,
+    ) -> Result<(), UnknownModeError> {
+        validate_in(&self.modes, kind, mode)
+    }
+
+    /// Returns `mode` if it is still valid for `kind`, or `None` (the adapter's default mode)
+    /// otherwise. Use this to revalidate a stored choice before sending it.
+    pub fn resolve(
+        &self,
+        kind: &BreakpointModeApplicability,
+        mode: Option<&str>,
+    ) -> Option<String> {
+        let mode = mode?;
+        self.validate(kind, mode).ok()?;
+        Some(mode.to_owned())
+    }
+
+    /// Applies a `capabilities` event, returning the ids of the modes that are no longer
+    /// advertised. Breakpoints using them have to be sent again without a mode.
+    pub fn on_capabilities_event(&mut self, event: &CapabilitiesEvent) -> Vec<String> {
+        let Some(new_modes) = &event.capabilities.breakpoint_modes else {
+            return Vec::new();
+        };
+        let mut removed = Vec::new();
+        let mut modes = Vec::with_capacity(new_modes.len());
+        for old in self.modes.drain(..) {
+            match new_modes.iter().find(|m| m.mode == old.mode) {
+                Some(new) => modes.push(new.clone()),
+                None => removed.push(old.mode),
+            }
+        }
+        for new in new_modes {
+            if !modes.iter().any(|m| m.mode == new.mode) {
+                modes.push(new.clone());
+            }
+        }
+        self.modes = modes;
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modes_stay_stable() {
+        let capabilities = Capabilities {
+            breakpoint_modes: Some(vec![
+                BreakpointMode::new(
+                    "hardware".to_owned(),
+                    "Hardware".to_owned(),
+                    vec![BreakpointModeApplicability::Source],
+                ),
+                BreakpointMode::new(
+                    "software".to_owned(),
+                    "Software".to_owned(),
+                    vec![
+                        BreakpointModeApplicability::Source,
+                        BreakpointModeApplicability::Instruction,
+                    ],
+                ),
+            ]),
+            ..Default::default()
+        };
+        let mut modes = BreakpointModes::new(&capabilities);
+        assert_eq!(
+            modes
+                .modes_for(&BreakpointModeApplicability::Instruction)
+                .len(),
+            1
+        );
+        assert!(modes
+            .validate(&BreakpointModeApplicability::Instruction, "hardware")
+            .is_err());
+        assert_eq!(
+            modes_for(&capabilities, &BreakpointModeApplicability::Instruction),
+            modes.modes_for(&BreakpointModeApplicability::Instruction)
+        );
+        assert!(validate_mode(
+            &capabilities,
+            &BreakpointModeApplicability::Source,
+            "hardware"
+        )
+        .is_ok());
+
+        let event = |modes| {
+            CapabilitiesEvent::new(Capabilities {
+                breakpoint_modes: modes,
+                ..Default::default()
+            })
+        };
+        assert!(modes.on_capabilities_event(&event(None)).is_empty());
+        let removed = modes.on_capabilities_event(&event(Some(vec![
+            BreakpointMode::new(
+                "logpoint".to_owned(),
+                "Logpoint".to_owned(),
+                vec![BreakpointModeApplicability::Source],
+            ),
+            BreakpointMode::new(
+                "software".to_owned(),
+                "Software".to_owned(),
+                vec![BreakpointModeApplicability::Source],
+            ),
+        ])));
+        assert_eq!(removed, vec!["hardware".to_owned()]);
+        let ids = modes
+            .all()
+            .iter()
+            .map(|m| m.mode.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["software", "logpoint"]);
+        assert_eq!(
+            modes.resolve(&BreakpointModeApplicability::Source, Some("hardware")),
+            None
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/// Implements a constructor taking the required fields of a struct, and a chainable setter for
+/// each optional field, named after the field. Used by the generated types.
+///
+/// ```
+/// # use dap_types::{EvaluateArguments, EvaluateArgumentsContext};
+/// let args = EvaluateArguments::new("x + 1".to_owned())
+///     .frame_id(1)
+///     .context(EvaluateArgumentsContext::Repl);
+/// ```
+macro_rules! impl_builder {
+    (
+        $ty:ident {
+            required { $($required:ident: $required_ty:ty,)* }
+            optional { $($optional:ident: $optional_ty:ty,)* }
+        }
+    ) => {
+        impl $ty {
+            #[allow(clippy::too_many_arguments)]
+            pub fn new($($required: $required_ty),*) -> Self {
+                Self {
+                    $($required,)*
+                    $($optional: None,)*
+                    #[cfg(feature = "extra-fields")]
+                    extra: serde_json::Map::new(),
+                }
+            }
+
+            $(
+                pub fn $optional(mut self, $optional: $optional_ty) -> Self {
+                    self.$optional = Some($optional);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use impl_builder;
+
+#[cfg(test)]
+mod tests {
+    use crate::{EvaluateArguments, EvaluateArgumentsContext, SetBreakpointsArguments, Source};
+
+    #[test]
+    fn test_builders() {
+        let args = EvaluateArguments::new("x + 1".to_owned())
+            .frame_id(1)
+            .context(EvaluateArgumentsContext::Repl);
+        assert_eq!(args.frame_id, Some(1));
+        assert_eq!(args.line, None);
+
+        let args = SetBreakpointsArguments::new(Source::new().path("/src/main.rs".to_owned()))
+            .source_modified(false);
+        assert_eq!(args.source.path.as_deref(), Some("/src/main.rs"));
+        assert_eq!(args.breakpoints, None);
+    }
+}
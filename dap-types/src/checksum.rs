@@ -0,0 +1,242 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    Capabilities, Checksum, ChecksumAlgorithm, SetBreakpointsArguments, Source, SourceBreakpoint,
+};
+
+/// The algorithms this crate can compute, in order of preference.
+pub const SUPPORTED_ALGORITHMS: [ChecksumAlgorithm; 4] = [
+    ChecksumAlgorithm::Sha256,
+    ChecksumAlgorithm::Sha1,
+    ChecksumAlgorithm::Md5,
+    ChecksumAlgorithm::Timestamp,
+];
+
+/// The algorithms supported both by the adapter (`supportedChecksumAlgorithms`) and by this
+/// crate, in order of preference.
+pub fn negotiated_algorithms(capabilities: &Capabilities) -> Vec<ChecksumAlgorithm> {
+    let Some(supported) = &capabilities.supported_checksum_algorithms else {
+        return Vec::new();
+    };
+    SUPPORTED_ALGORITHMS
+        .into_iter()
+        .filter(|algorithm| supported.contains(algorithm))
+        .collect()
+}
+
+/// Computes the hexadecimal digest of `contents`, or returns `None` for
+/// [`ChecksumAlgorithm::Timestamp`], which is not computed from the contents.
+pub fn compute(algorithm: ChecksumAlgorithm, contents: &[u8]) -> Option<String> {
+    let digest = match algorithm {
+        ChecksumAlgorithm::Md5 => Md5::digest(contents).to_vec(),
+        ChecksumAlgorithm::Sha1 => Sha1::digest(contents).to_vec(),
+        ChecksumAlgorithm::Sha256 => Sha256::digest(contents).to_vec(),
+        ChecksumAlgorithm::Timestamp => return None,
+    };
+    Some(digest.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+/// Formats a modification time as a `timestamp` checksum.
+///
+/// The protocol only specifies that checksums are hexadecimal values, and leaves the meaning of
+/// timestamps to the adapter. This uses the seconds since the Unix epoch, in hexadecimal.
+pub fn timestamp(modified: SystemTime) -> String {
+    format!("{:x}", unix_seconds(modified))
+}
+
+/// Computes checksums of the file at `path` with each of `algorithms`.
+pub fn checksums_for_file(
+    path: impl AsRef<Path>,
+    algorithms: &[ChecksumAlgorithm],
+) -> io::Result<Vec<Checksum>> {
+    let path = path.as_ref();
+    if algorithms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let contents = if algorithms
+        .iter()
+        .any(|a| *a != ChecksumAlgorithm::Timestamp)
+    {
+        fs::read(path)?
+    } else {
+        Vec::new()
+    };
+    let mut checksums = Vec::with_capacity(algorithms.len());
+    for &algorithm in algorithms {
+        let checksum = match compute(algorithm, &contents) {
+            Some(checksum) => checksum,
+            None => timestamp(fs::metadata(path)?.modified()?),
+        };
+        checksums.push(Checksum::new(algorithm, checksum));
+    }
+    Ok(checksums)
+}
+
+/// Fills `source.checksums` for the local file at `source.path`, using the algorithms
+/// negotiated with [`negotiated_algorithms`]. Existing checksums are replaced; if the adapter
+/// supports none of the algorithms, they are left unchanged.
+pub fn attach_checksums(source: &mut Source, capabilities: &Capabilities) -> io::Result<()> {
+    let algorithms = negotiated_algorithms(capabilities);
+    let Some(path) = &source.path else {
+        return Ok(());
+    };
+    if algorithms.is_empty() {
+        return Ok(());
+    }
+    source.checksums = Some(checksums_for_file(path, &algorithms)?);
+    Ok(())
+}
+
+/// Builds arguments for a `setBreakpoints` request, attaching checksums of the local file so
+/// that the adapter can reject breakpoints if the file does not match the running program.
+///
+/// If the file can not be read, the breakpoints are sent without checksums.
+pub fn set_breakpoints_arguments(
+    mut source: Source,
+    breakpoints: Vec<SourceBreakpoint>,
+    source_modified: bool,
+    capabilities: &Capabilities,
+) -> SetBreakpointsArguments {
+    if attach_checksums(&mut source, capabilities).is_err() {
+        source.checksums = None;
+    }
+    let mut args = SetBreakpointsArguments::new(source).breakpoints(breakpoints);
+    args.source_modified = source_modified.then_some(true);
+    args
+}
+
+/// The result of comparing checksums reported by the adapter with a local file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumStatus {
+    /// All verifiable checksums match.
+    Match,
+    /// At least one checksum does not match.
+    Mismatch,
+    /// None of the checksums could be verified, e.g. because there are none, or only timestamps
+    /// and the modification time is not known.
+    Unverified,
+}
+
+/// Compares `checksums` with a file's contents and, if known, its modification time.
+///
+/// Content hashes decide the result. Timestamps are only compared if there is no hash, since
+/// a file can be touched without changing its contents.
+pub fn verify(
+    checksums: &[Checksum],
+    contents: &[u8],
+    modified: Option<SystemTime>,
+) -> ChecksumStatus {
+    let (timestamps, hashes): (Vec<_>, Vec<_>) = checksums
+        .iter()
+        .partition(|checksum| checksum.algorithm == ChecksumAlgorithm::Timestamp);
+    if !hashes.is_empty() {
+        let matches = hashes.iter().all(|checksum| {
+            compute(checksum.algorithm, contents)
+                .is_some_and(|actual| actual.eq_ignore_ascii_case(checksum.checksum.trim()))
+        });
+        return if matches {
+            ChecksumStatus::Match
+        } else {
+            ChecksumStatus::Mismatch
+        };
+    }
+    let Some(modified) = modified.filter(|_| !timestamps.is_empty()) else {
+        return ChecksumStatus::Unverified;
+    };
+    let seconds = unix_seconds(modified);
+    if timestamps
+        .iter()
+        .all(|checksum| u64::from_str_radix(checksum.checksum.trim(), 16).ok() == Some(seconds))
+    {
+        ChecksumStatus::Match
+    } else {
+        ChecksumStatus::Mismatch
+    }
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_and_verify() {
+        assert_eq!(
+            compute(ChecksumAlgorithm::Md5, b"abc").unwrap(),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert_eq!(
+            compute(ChecksumAlgorithm::Sha1, b"abc").unwrap(),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            compute(ChecksumAlgorithm::Sha256, b"abc").unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let checksums = [
+            Checksum::new(
+                ChecksumAlgorithm::Md5,
+                "900150983CD24FB0D6963F7D28E17F72".to_owned(),
+            ),
+            Checksum::new(ChecksumAlgorithm::Timestamp, "1".to_owned()),
+        ];
+        assert_eq!(verify(&checksums, b"abc", None), ChecksumStatus::Match);
+        assert_eq!(verify(&checksums, b"abd", None), ChecksumStatus::Mismatch);
+        // The hash decides, even though the file was touched.
+        assert_eq!(
+            verify(&checksums, b"abc", Some(UNIX_EPOCH)),
+            ChecksumStatus::Match
+        );
+        assert_eq!(verify(&[], b"abc", None), ChecksumStatus::Unverified);
+
+        let modified = UNIX_EPOCH + std::time::Duration::from_secs(0x1f);
+        assert_eq!(timestamp(modified), "1f");
+        let timestamps = [Checksum::new(
+            ChecksumAlgorithm::Timestamp,
+            "001F".to_owned(),
+        )];
+        assert_eq!(
+            verify(&timestamps, b"", Some(modified)),
+            ChecksumStatus::Match
+        );
+        assert_eq!(
+            verify(&timestamps, b"", Some(UNIX_EPOCH)),
+            ChecksumStatus::Mismatch
+        );
+        assert_eq!(verify(&timestamps, b"", None), ChecksumStatus::Unverified);
+    }
+
+    #[test]
+    fn test_set_breakpoints_arguments_with_checksums() {
+        let path = std::env::temp_dir().join(format!("dap-types-checksum-{}", std::process::id()));
+        fs::write(&path, "abc").unwrap();
+        let source = Source::new().path(path.to_string_lossy().into_owned());
+        let capabilities = Capabilities {
+            supported_checksum_algorithms: Some(vec![
+                ChecksumAlgorithm::Md5,
+                ChecksumAlgorithm::Sha1,
+            ]),
+            ..Default::default()
+        };
+        let args = set_breakpoints_arguments(source.clone(), Vec::new(), false, &capabilities);
+        let checksums = args.source.checksums.unwrap();
+        assert_eq!(checksums.len(), 2);
+        assert_eq!(checksums[0].algorithm, ChecksumAlgorithm::Sha1);
+        assert_eq!(verify(&checksums, b"abc", None), ChecksumStatus::Match);
+
+        fs::remove_file(&path).unwrap();
+        let args = set_breakpoints_arguments(source, Vec::new(), false, &capabilities);
+        assert_eq!(args.source.checksums, None);
+    }
+}
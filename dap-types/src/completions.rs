@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+use crate::{Capabilities, CompletionItem, CompletionsArguments};
+
+/// Returns whether typing `c` should trigger a `completions` request. If the adapter does not
+/// specify `completionTriggerCharacters`, `.` is assumed, as most clients do.
+pub fn is_trigger_character(capabilities: &Capabilities, c: char) -> bool {
+    if !capabilities.supports_completions_request.unwrap_or(false) {
+        return false;
+    }
+    match &capabilities.completion_trigger_characters {
+        Some(characters) => characters.iter().any(|t| t.chars().eq([c])),
+        None => c == '.',
+    }
+}
+
+/// A REPL input line with the position of the cursor, as a byte offset into `text`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputLine {
+    pub text: String,
+    pub cursor: usize,
+    /// Whether columns are 1-based, as announced with `columnsStartAt1` in the `initialize`
+    /// request. Defaults to true in the protocol.
+    pub columns_start_at1: bool,
+}
+
+/// The input line after a completion has been applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompletedLine {
+    pub text: String,
+    /// The new cursor position, as a byte offset. This is the end of `selection`.
+    pub cursor: usize,
+    /// The range to select in the new text, as byte offsets. Empty if nothing is to be selected.
+    pub selection: Range<usize>,
+}
+
+impl InputLine {
+    pub fn new(text: impl Into<String>, cursor: usize, columns_start_at1: bool) -> Self {
+        let text = text.into();
+        let cursor = floor_char_boundary(&text, cursor);
+        Self {
+            text,
+            cursor,
+            columns_start_at1,
+        }
+    }
+
+    /// Arguments for a `completions` request at the cursor.
+    pub fn completions_arguments(&self, frame_id: Option<i64>) -> CompletionsArguments {
+        let mut args =
+            CompletionsArguments::new(self.text.clone(), self.byte_to_column(self.cursor));
+        args.frame_id = frame_id;
+        args
+    }
+
+    /// Applies a completion item returned for [`InputLine::completions_arguments`].
+    pub fn apply(&self, item: &CompletionItem) -> CompletedLine {
+        let range = self.replaced_range(item);
+        let insert = insert_text(item);
+        let mut text = String::with_capacity(self.text.len() + insert.len());
+        text.push_str(&self.text[..range.start]);
+        text.push_str(insert);
+        text.push_str(&self.text[range.end..]);
+
+        let selection_start = item
+            .selection_start
+            .map_or(insert.len(), |s| utf16_to_byte(insert, s));
+        let selection_end = item.selection_length.map_or(selection_start, |l| {
+            selection_start + utf16_to_byte(&insert[selection_start..], l)
+        });
+        let selection = range.start + selection_start..range.start + selection_end;
+        CompletedLine {
+            text,
+            cursor: selection.end,
+            selection,
+        }
+    }
+
+    /// Filters items to the ones matching the text between their start position and the cursor
+    /// (ignoring case), and sorts them by `sortText`, falling back to `label`.
+    pub fn filter_and_sort<'a>(&self, items: &'a [CompletionItem]) -> Vec<&'a CompletionItem> {
+        let mut matching = items
+            .iter()
+            .filter(|item| {
+                let range = self.replaced_range(item);
+                let typed = &self.text[range.start..self.cursor.max(range.start)];
+                let candidate = insert_text(item);
+                candidate.to_lowercase().starts_with(&typed.to_lowercase())
+            })
+            .collect::<Vec<_>>();
+        matching.sort_by(|a, b| sort_key(a).cmp(sort_key(b)));
+        matching
+    }
+
+    /// The byte range of `text` that the item replaces.
+    fn replaced_range(&self, item: &CompletionItem) -> Range<usize> {
+        let start = item
+            .start
+            .map_or(self.cursor, |start| self.column_to_byte(start));
+        let length = item.length.unwrap_or(0);
+        let end = start + utf16_to_byte(&self.text[start..], length);
+        start..end
+    }
+
+    fn byte_to_column(&self, byte: usize) -> u64 {
+        let units = self.text[..byte].encode_utf16().count() as u64;
+        units + u64::from(self.columns_start_at1)
+    }
+
+    fn column_to_byte(&self, column: u64) -> usize {
+        let units = column.saturating_sub(u64::from(self.columns_start_at1));
+        utf16_to_byte(&self.text, units)
+    }
+}
+
+fn insert_text(item: &CompletionItem) -> &str {
+    match &item.text {
+        Some(text) if !text.is_empty() => text,
+        _ => &item.label,
+    }
+}
+
+fn sort_key(item: &CompletionItem) -> &str {
+    match &item.sort_text {
+        Some(sort_text) if !sort_text.is_empty() => sort_text,
+        _ => &item.label,
+    }
+}
+
+/// Converts an offset in UTF-16 code units into a byte offset, clamping it to the length of the
+/// text. An offset in the middle of a surrogate pair is rounded down.
+fn utf16_to_byte(text: &str, units: u64) -> usize {
+    let mut remaining = units;
+    for (index, c) in text.char_indices() {
+        let width = c.len_utf16() as u64;
+        if remaining < width {
+            return index;
+        }
+        remaining -= width;
+    }
+    text.len()
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_with_utf16_columns() {
+        // "😀" is two UTF-16 code units and four bytes.
+        let line = InputLine::new("😀.fo + x", "😀.fo".len(), true);
+        assert_eq!(line.completions_arguments(None).column, 6);
+
+        let completed = line.apply(&CompletionItem::new("foo".to_owned()).start(4).length(2));
+        assert_eq!(completed.text, "😀.foo + x");
+        assert_eq!(completed.cursor, "😀.foo".len());
+
+        let with_selection = CompletionItem::new("bar(arg)".to_owned())
+            .start(4)
+            .length(2)
+            .selection_start(4)
+            .selection_length(3);
+        let completed = line.apply(&with_selection);
+        assert_eq!(&completed.text[completed.selection], "arg");
+    }
+
+    #[test]
+    fn test_filter_and_sort() {
+        let line = InputLine::new("x.Fo", 4, false);
+        let items = vec![
+            CompletionItem::new("format".to_owned())
+                .start(2)
+                .length(2)
+                .sort_text("z".to_owned()),
+            CompletionItem::new("bar".to_owned()).start(2).length(2),
+            CompletionItem::new("foo".to_owned()).start(2).length(2),
+        ];
+        let labels = line
+            .filter_and_sort(&items)
+            .into_iter()
+            .map(|i| i.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["foo", "format"]);
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt;
+
+use crate::{
+    Breakpoint, BreakpointEvent, BreakpointEventReason, Capabilities, DataBreakpoint,
+    DataBreakpointAccessType, DataBreakpointInfoArguments, DataBreakpointInfoResponse,
+    SetDataBreakpointsArguments, SetDataBreakpointsResponse,
+};
+
+/// What to watch with a data breakpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataBreakpointTarget {
+    /// A child `name` of the container `variables_reference`.
+    Variable {
+        variables_reference: i64,
+        name: String,
+    },
+    /// An expression, evaluated in the scope of `frame_id`, or globally if it is `None`.
+    Expression {
+        expression: String,
+        frame_id: Option<i64>,
+    },
+    /// `bytes` bytes of memory starting at `address` (decimal, or hexadecimal prefixed with `0x`).
+    Address { address: String, bytes: u64 },
+}
+
+/// Error returned when a data breakpoint can not be set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataBreakpointError {
+    /// The adapter does not support `supportsDataBreakpoints`.
+    NotSupported,
+    /// An address target or byte count was given, but the adapter does not support
+    /// `supportsDataBreakpointBytes`.
+    BytesNotSupported,
+    /// The adapter returned no `dataId`. Contains the adapter's `description` of why.
+    Unavailable(String),
+    /// The access type is not one of the `accessTypes` returned by the adapter.
+    AccessTypeNotSupported(DataBreakpointAccessType),
+}
+
+impl fmt::Display for DataBreakpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataBreakpointError::NotSupported => {
+                write!(f, "debug adapter does not support data breakpoints")
+            }
+            DataBreakpointError::BytesNotSupported => {
+                write!(
+                    f,
+                    "debug adapter does not support data breakpoints on memory ranges"
+                )
+            }
+            DataBreakpointError::Unavailable(description) => {
+                write!(f, "data breakpoint not available: {description}")
+            }
+            DataBreakpointError::AccessTypeNotSupported(access_type) => {
+                write!(
+                    f,
+                    "access type {access_type:?} is not supported for this data breakpoint"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataBreakpointError {}
+
+/// A data breakpoint known to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataBreakpointEntry {
+    pub breakpoint: DataBreakpoint,
+    /// The adapter's `description` of the watched data.
+    pub description: String,
+    /// Whether the breakpoint is kept for later sessions (`canPersist`).
+    pub persistent: bool,
+    /// The breakpoint as last reported by the adapter, or `None` if it has not been sent yet.
+    pub status: Option<Breakpoint>,
+}
+
+/// Runs the data breakpoint workflow: a `dataBreakpointInfo` request for the data to watch,
+/// followed by a `setDataBreakpoints` request replacing the full set.
+///
+/// ```text
+/// let args = manager.info_arguments(&capabilities, target)?;
+/// // send `dataBreakpointInfo` with `args`, then:
+/// manager.add(&response, Some(DataBreakpointAccessType::Write), None, None)?;
+/// // send `setDataBreakpoints` with `manager.set_arguments()`, then:
+/// manager.on_set_response(&response);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DataBreakpointManager {
+    entries: Vec<DataBreakpointEntry>,
+}
+
+impl DataBreakpointManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[DataBreakpointEntry] {
+        &self.entries
+    }
+
+    pub fn get(&self, data_id: &str) -> Option<&DataBreakpointEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.breakpoint.data_id == data_id)
+    }
+
+    /// Arguments for the `dataBreakpointInfo` request for `target`.
+    pub fn info_arguments(
+        &self,
+        capabilities: &Capabilities,
+        target: DataBreakpointTarget,
+    ) -> Result<DataBreakpointInfoArguments, DataBreakpointError> {
+        if !capabilities.supports_data_breakpoints.unwrap_or(false) {
+            return Err(DataBreakpointError::NotSupported);
+        }
+        let mut args = DataBreakpointInfoArguments::new(String::new());
+        match target {
+            DataBreakpointTarget::Variable {
+                variables_reference,
+                name,
+            } => {
+                args.variables_reference = Some(variables_reference);
+                args.name = name;
+            }
+            DataBreakpointTarget::Expression {
+                expression,
+                frame_id,
+            } => {
+                args.name = expression;
+                args.frame_id = frame_id;
+            }
+            DataBreakpointTarget::Address { address, bytes } => {
+                if !capabilities.supports_data_breakpoint_bytes.unwrap_or(false) {
+                    return Err(DataBreakpointError::BytesNotSupported);
+                }
+                args.name = address;
+                args.bytes = Some(bytes);
+                args.as_address = Some(true);
+            }
+        }
+        Ok(args)
+    }
+
+    /// Adds a data breakpoint from a `dataBreakpointInfo` response, replacing any breakpoint on
+    /// the same data. `access_type` must be one of the response's `accessTypes`, if the adapter
+    /// listed any.
+    pub fn add(
+        &mut self,
+        info: &DataBreakpointInfoResponse,
+        access_type: Option<DataBreakpointAccessType>,
+        condition: Option<String>,
+        hit_condition: Option<String>,
+    ) -> Result<&DataBreakpointEntry, DataBreakpointError> {
+        let Some(data_id) = &info.data_id else {
+            return Err(DataBreakpointError::Unavailable(info.description.clone()));
+        };
+        if let (Some(access_type), Some(available)) = (access_type, &info.access_types) {
+            if !available.contains(&access_type) {
+                return Err(DataBreakpointError::AccessTypeNotSupported(access_type));
+            }
+        }
+        let entry = DataBreakpointEntry {
+            breakpoint: {
+                let mut breakpoint = DataBreakpoint::new(data_id.clone());
+                breakpoint.access_type = access_type;
+                breakpoint.condition = condition;
+                breakpoint.hit_condition = hit_condition;
+                breakpoint
+            },
+            description: info.description.clone(),
+            persistent: info.can_persist.unwrap_or(false),
+            status: None,
+        };
+        let index = match self.position(data_id) {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        Ok(&self.entries[index])
+    }
+
+    pub fn remove(&mut self, data_id: &str) -> Option<DataBreakpointEntry> {
+        let index = self.position(data_id)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Arguments for a `setDataBreakpoints` request sending the full set.
+    pub fn set_arguments(&self) -> SetDataBreakpointsArguments {
+        SetDataBreakpointsArguments::new(
+            self.entries
+                .iter()
+                .map(|entry| entry.breakpoint.clone())
+                .collect(),
+        )
+    }
+
+    /// Records the breakpoints returned for the request built by
+    /// [`DataBreakpointManager::set_arguments`], which correspond to the entries by position.
+    pub fn on_set_response(&mut self, response: &SetDataBreakpointsResponse) {
+        for (entry, breakpoint) in self.entries.iter_mut().zip(&response.breakpoints) {
+            entry.status = Some(breakpoint.clone());
+        }
+    }
+
+    /// Applies a `breakpoint` event to the entry with the same breakpoint `id`. Returns whether
+    /// an entry was updated.
+    pub fn on_breakpoint_event(&mut self, event: &BreakpointEvent) -> bool {
+        let Some(id) = event.breakpoint.id else {
+            return false;
+        };
+        let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.status.as_ref().and_then(|s| s.id) == Some(id))
+        else {
+            return false;
+        };
+        match event.reason {
+            BreakpointEventReason::Removed => entry.status = None,
+            _ => entry.status = Some(event.breakpoint.clone()),
+        }
+        true
+    }
+
+    /// Forgets breakpoints that can not persist across sessions, and the adapter's status of the
+    /// others. Call this when a session ends; the persistent breakpoints are sent again with
+    /// [`DataBreakpointManager::set_arguments`] in the next session.
+    pub fn end_session(&mut self) {
+        self.entries.retain(|entry| entry.persistent);
+        for entry in &mut self.entries {
+            entry.status = None;
+        }
+    }
+
+    fn position(&self, data_id: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.breakpoint.data_id == data_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_arguments_validation() {
+        let manager = DataBreakpointManager::new();
+        let mut capabilities = Capabilities {
+            supports_data_breakpoints: Some(true),
+            ..Default::default()
+        };
+        let address = DataBreakpointTarget::Address {
+            address: "0x1000".to_owned(),
+            bytes: 4,
+        };
+        assert_eq!(
+            manager.info_arguments(&capabilities, address.clone()),
+            Err(DataBreakpointError::BytesNotSupported)
+        );
+        capabilities.supports_data_breakpoint_bytes = Some(true);
+        let args = manager.info_arguments(&capabilities, address).unwrap();
+        assert_eq!(args.as_address, Some(true));
+        assert_eq!(args.bytes, Some(4));
+    }
+
+    #[test]
+    fn test_workflow_and_persistence() {
+        let mut manager = DataBreakpointManager::new();
+        let x = DataBreakpointInfoResponse::new(Some("x".to_owned()), "x".to_owned())
+            .access_types(vec![DataBreakpointAccessType::Write])
+            .can_persist(true);
+        let y = DataBreakpointInfoResponse::new(Some("y".to_owned()), "y".to_owned());
+        assert_eq!(
+            manager.add(&x, Some(DataBreakpointAccessType::Read), None, None),
+            Err(DataBreakpointError::AccessTypeNotSupported(
+                DataBreakpointAccessType::Read
+            ))
+        );
+        manager
+            .add(&x, Some(DataBreakpointAccessType::Write), None, None)
+            .unwrap();
+        manager.add(&y, None, None, None).unwrap();
+        assert_eq!(manager.set_arguments().breakpoints.len(), 2);
+
+        manager.on_set_response(&SetDataBreakpointsResponse::new(vec![
+            Breakpoint::new(false).id(1),
+            Breakpoint::new(true).id(2),
+        ]));
+        assert!(manager.on_breakpoint_event(&BreakpointEvent::new(
+            BreakpointEventReason::Changed,
+            Breakpoint::new(true).id(1),
+        )));
+        assert!(manager.get("x").unwrap().status.as_ref().unwrap().verified);
+
+        manager.end_session();
+        assert_eq!(manager.entries().len(), 1);
+        assert_eq!(manager.entries()[0].breakpoint.data_id, "x");
+        assert_eq!(manager.entries()[0].status, None);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::instruction_breakpoints::resolve_address;
+use crate::{
+    DisassembleArguments, DisassembleResponse, DisassembledInstruction,
+    DisassembledInstructionPresentationHint, InstructionBreakpoint, Source,
+};
+
+/// Parses an address or memory reference in the format used by `DisassembledInstruction.address`:
+/// hexadecimal if prefixed with `0x`, decimal otherwise.
+pub fn parse_address(address: &str) -> Option<u64> {
+    let address = address.trim();
+    match address
+        .strip_prefix("0x")
+        .or_else(|| address.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => address.parse().ok(),
+    }
+}
+
+/// Formats an address as a memory reference that can be passed back to the adapter.
+pub fn format_address(address: u64) -> String {
+    format!("0x{address:x}")
+}
+
+/// A disassembled instruction in a [`DisassemblyCache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionRow {
+    pub address: u64,
+    pub instruction: DisassembledInstruction,
+    /// The source location of the instruction. Unlike `instruction.location`, this is filled in
+    /// for instructions that omit it because they map to the same source as the previous one.
+    pub location: Option<Source>,
+    /// Whether the adapter returned this instruction as an `invalid` placeholder, e.g. for
+    /// unreadable memory.
+    pub invalid: bool,
+}
+
+/// Consecutive rows that share the same symbol, source and line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionGroup<'a> {
+    pub symbol: Option<&'a str>,
+    pub location: Option<&'a Source>,
+    pub line: Option<u64>,
+    pub rows: &'a [InstructionRow],
+}
+
+/// A windowed cache of disassembled instructions, ordered and deduplicated by address.
+///
+/// The cache starts with a window around an instruction pointer (typically the
+/// `instructionPointerReference` of the current stack frame), and can be extended in both
+/// directions as the view is scrolled.
+#[derive(Debug, Clone, Default)]
+pub struct DisassemblyCache {
+    rows: Vec<InstructionRow>,
+    resolve_symbols: bool,
+}
+
+impl DisassemblyCache {
+    pub fn new(resolve_symbols: bool) -> Self {
+        Self {
+            rows: Vec::new(),
+            resolve_symbols,
+        }
+    }
+
+    pub fn rows(&self) -> &[InstructionRow] {
+        &self.rows
+    }
+
+    /// Arguments to fetch `before` instructions preceding `memory_reference` and `after`
+    /// instructions starting at it.
+    pub fn request_around(
+        &self,
+        memory_reference: &str,
+        before: u64,
+        after: u64,
+    ) -> DisassembleArguments {
+        self.request(
+            memory_reference.to_owned(),
+            -(before as i64),
+            before + after,
+        )
+    }
+
+    /// Arguments to fetch `count` instructions preceding the first cached valid instruction, or
+    /// `None` if the cache is empty.
+    pub fn request_above(&self, count: u64) -> Option<DisassembleArguments> {
+        let first = self.rows.iter().find(|row| !row.invalid)?;
+        Some(self.request(format_address(first.address), -(count as i64), count))
+    }
+
+    /// Arguments to fetch `count` instructions following the last cached valid instruction, or
+    /// `None` if the cache is empty.
+    pub fn request_below(&self, count: u64) -> Option<DisassembleArguments> {
+        let last = self.rows.iter().rev().find(|row| !row.invalid)?;
+        Some(self.request(format_address(last.address), 1, count))
+    }
+
+    /// Adds the instructions of a `disassemble` response to the cache.
+    ///
+    /// Instructions at addresses that are already cached replace the cached ones, except that an
+    /// `invalid` placeholder never replaces a valid instruction. Instructions whose address can
+    /// not be parsed are dropped.
+    pub fn insert(&mut self, response: &DisassembleResponse) {
+        let mut location: Option<Source> = None;
+        for instruction in &response.instructions {
+            if instruction.location.is_some() {
+                location = instruction.location.clone();
+            }
+            let Some(address) = parse_address(&instruction.address) else {
+                continue;
+            };
+            let row = InstructionRow {
+                address,
+                instruction: instruction.clone(),
+                location: location.clone(),
+                invalid: instruction.presentation_hint
+                    == Some(DisassembledInstructionPresentationHint::Invalid),
+            };
+            match self.rows.binary_search_by_key(&address, |r| r.address) {
+                Ok(index) => {
+                    if !row.invalid || self.rows[index].invalid {
+                        self.rows[index] = row;
+                    }
+                }
+                Err(index) => self.rows.insert(index, row),
+            }
+        }
+    }
+
+    pub fn row_index(&self, address: u64) -> Option<usize> {
+        self.rows.binary_search_by_key(&address, |r| r.address).ok()
+    }
+
+    /// Groups consecutive rows by symbol, source and line, e.g. to render source line headers.
+    pub fn groups(&self) -> Vec<InstructionGroup<'_>> {
+        fn same_group(a: &InstructionRow, b: &InstructionRow) -> bool {
+            let source = |row: &InstructionRow| {
+                row.location
+                    .as_ref()
+                    .map(|l| (l.path.clone(), l.source_reference))
+            };
+            a.instruction.symbol == b.instruction.symbol
+                && a.instruction.line == b.instruction.line
+                && source(a) == source(b)
+        }
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for index in 1..=self.rows.len() {
+            if index == self.rows.len() || !same_group(&self.rows[index], &self.rows[start]) {
+                let first = &self.rows[start];
+                groups.push(InstructionGroup {
+                    symbol: first.instruction.symbol.as_deref(),
+                    location: first.location.as_ref(),
+                    line: first.instruction.line,
+                    rows: &self.rows[start..index],
+                });
+                start = index;
+            }
+        }
+        groups
+    }
+
+    /// Maps instruction breakpoints to the indices of the rows they are set on. Breakpoints
+    /// whose `instructionReference` is not an address, or whose address is not cached, are
+    /// skipped.
+    pub fn breakpoint_rows<'a>(
+        &self,
+        breakpoints: &'a [InstructionBreakpoint],
+    ) -> Vec<(usize, &'a InstructionBreakpoint)> {
+        breakpoints
+            .iter()
+            .filter_map(|breakpoint| {
+                let address = resolve_address(breakpoint)?;
+                Some((self.row_index(address)?, breakpoint))
+            })
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    fn request(
+        &self,
+        memory_reference: String,
+        instruction_offset: i64,
+        instruction_count: u64,
+    ) -> DisassembleArguments {
+        let mut args = DisassembleArguments::new(memory_reference, instruction_count);
+        args.instruction_offset = (instruction_offset != 0).then_some(instruction_offset);
+        args.resolve_symbols = self.resolve_symbols.then_some(true);
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_dedupes_and_groups() {
+        let mut cache = DisassemblyCache::new(true);
+        let args = cache.request_around("0x1004", 2, 2);
+        assert_eq!(args.instruction_offset, Some(-2));
+        assert_eq!(args.instruction_count, 4);
+
+        let nop = |address: &str| {
+            DisassembledInstruction::new(address.to_owned(), "nop".to_owned())
+                .symbol("main".to_owned())
+        };
+        let invalid = DisassembledInstructionPresentationHint::Invalid;
+        cache.insert(&DisassembleResponse::new(vec![
+            nop("0x1000").line(1).presentation_hint(invalid.clone()),
+            nop("0x1004").line(2),
+            nop("0x1008").line(2),
+        ]));
+        cache.insert(&DisassembleResponse::new(vec![
+            nop("4096").line(1),
+            nop("0x1008").line(2).presentation_hint(invalid),
+            nop("0x100c").line(3),
+        ]));
+        assert_eq!(cache.rows().len(), 4);
+        assert!(cache.rows().iter().all(|r| !r.invalid));
+        assert_eq!(
+            cache.request_below(8).unwrap().memory_reference,
+            "0x100c".to_owned()
+        );
+
+        let groups = cache.groups();
+        assert_eq!(
+            groups.iter().map(|g| g.rows.len()).collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+
+        let breakpoints = [InstructionBreakpoint::new("0x1000".to_owned()).offset(8)];
+        assert_eq!(cache.breakpoint_rows(&breakpoints)[0].0, 2);
+    }
+}
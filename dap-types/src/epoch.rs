@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::{
+    EvaluateArguments, EvaluateArgumentsContext, RestartFrameArguments, Scope, ScopesArguments,
+    SetVariableArguments, StackFrame, StoppedEvent, Variable, VariablesArguments,
+};
+
+/// Identifies one period during which the debuggee is suspended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Epoch(u64);
+
+/// The id of a `StackFrame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FrameId(pub i64);
+
+/// A `variablesReference` of a `Scope`, `Variable` or evaluation result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VariablesReference(pub i64);
+
+/// An object reference that is only valid while the debuggee stays suspended, tagged with the
+/// epoch in which it was obtained.
+///
+/// The protocol specifies that frame ids and variable references are invalidated as soon as
+/// execution resumes. Requests built from a `Suspended` value through [`SessionEpoch`] fail
+/// locally with a [`StaleReferenceError`] instead of being sent with a reference that the
+/// adapter may reject or, worse, resolve to an unrelated object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Suspended<T> {
+    value: T,
+    epoch: Epoch,
+}
+
+impl<T> Suspended<T> {
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the reference without checking whether it is still valid.
+    pub fn get_unchecked(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Error returned when a reference is used after the debuggee resumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaleReferenceError {
+    /// The epoch the reference was obtained in.
+    pub reference_epoch: Epoch,
+    /// The current epoch, or `None` if the debuggee is running.
+    pub current_epoch: Option<Epoch>,
+}
+
+impl fmt::Display for StaleReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.current_epoch {
+            Some(current) => write!(
+                f,
+                "reference from stop {} used after the debuggee resumed and stopped again (now stop {})",
+                self.reference_epoch.0, current.0
+            ),
+            None => write!(
+                f,
+                "reference from stop {} used while the debuggee is running",
+                self.reference_epoch.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StaleReferenceError {}
+
+/// Tracks whether the debuggee is suspended, and hands out and checks [`Suspended`] references.
+///
+/// Call [`SessionEpoch::on_stopped`] for every `stopped` event, and [`SessionEpoch::on_resumed`]
+/// for every `continued` event and whenever a request that resumes execution (`continue`,
+/// `next`, `stepIn`, `stepOut`, `stepBack`, `reverseContinue`, `goto`, `restartFrame`) is sent.
+#[derive(Debug, Clone, Default)]
+pub struct SessionEpoch {
+    current: u64,
+    suspended: bool,
+    /// Whether a stop in the current epoch reported that all threads stopped.
+    all_threads_stopped: bool,
+}
+
+impl SessionEpoch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current epoch, or `None` if the debuggee is running.
+    pub fn current(&self) -> Option<Epoch> {
+        self.suspended.then_some(Epoch(self.current))
+    }
+
+    /// Returns the epoch the stop belongs to.
+    ///
+    /// A new epoch starts if execution resumed since the current one started. Otherwise the stop
+    /// is part of the current epoch, like a second thread stopping while the first one is still
+    /// suspended, and references obtained so far stay valid. The exception is a stop after one
+    /// that reported `allThreadsStopped`: since nothing could have stopped without resuming
+    /// first, a resume was missed and a new epoch starts.
+    pub fn on_stopped(&mut self, event: &StoppedEvent) -> Epoch {
+        if !self.suspended || self.all_threads_stopped {
+            self.current += 1;
+            self.suspended = true;
+            self.all_threads_stopped = false;
+        }
+        self.all_threads_stopped |= event.all_threads_stopped == Some(true);
+        Epoch(self.current)
+    }
+
+    pub fn on_resumed(&mut self) {
+        self.suspended = false;
+        self.all_threads_stopped = false;
+    }
+
+    /// Tags a reference obtained from the adapter with the current epoch. Returns an error if the
+    /// debuggee is running, in which case the reference is already stale.
+    pub fn tag<T>(&self, value: T) -> Result<Suspended<T>, StaleReferenceError> {
+        let epoch = Epoch(self.current);
+        if !self.suspended {
+            return Err(StaleReferenceError {
+                reference_epoch: epoch,
+                current_epoch: None,
+            });
+        }
+        Ok(Suspended { value, epoch })
+    }
+
+    pub fn frame(&self, frame: &StackFrame) -> Result<Suspended<FrameId>, StaleReferenceError> {
+        self.tag(FrameId(frame.id))
+    }
+
+    pub fn scope(
+        &self,
+        scope: &Scope,
+    ) -> Result<Suspended<VariablesReference>, StaleReferenceError> {
+        self.tag(VariablesReference(scope.variables_reference))
+    }
+
+    /// Tags the reference of an expandable variable, or returns `Ok(None)` for a variable
+    /// without children.
+    pub fn variable(
+        &self,
+        variable: &Variable,
+    ) -> Result<Option<Suspended<VariablesReference>>, StaleReferenceError> {
+        if variable.variables_reference <= 0 {
+            return Ok(None);
+        }
+        self.tag(VariablesReference(variable.variables_reference))
+            .map(Some)
+    }
+
+    /// Returns the reference if it was obtained in the current epoch.
+    pub fn check<'a, T>(&self, reference: &'a Suspended<T>) -> Result<&'a T, StaleReferenceError> {
+        if self.current() == Some(reference.epoch) {
+            Ok(&reference.value)
+        } else {
+            Err(StaleReferenceError {
+                reference_epoch: reference.epoch,
+                current_epoch: self.current(),
+            })
+        }
+    }
+
+    pub fn scopes_arguments(
+        &self,
+        frame: &Suspended<FrameId>,
+    ) -> Result<ScopesArguments, StaleReferenceError> {
+        Ok(ScopesArguments::new(self.check(frame)?.0))
+    }
+
+    pub fn restart_frame_arguments(
+        &self,
+        frame: &Suspended<FrameId>,
+    ) -> Result<RestartFrameArguments, StaleReferenceError> {
+        Ok(RestartFrameArguments::new(self.check(frame)?.0))
+    }
+
+    pub fn variables_arguments(
+        &self,
+        reference: &Suspended<VariablesReference>,
+    ) -> Result<VariablesArguments, StaleReferenceError> {
+        Ok(VariablesArguments::new(self.check(reference)?.0))
+    }
+
+    pub fn set_variable_arguments(
+        &self,
+        reference: &Suspended<VariablesReference>,
+        name: String,
+        value: String,
+    ) -> Result<SetVariableArguments, StaleReferenceError> {
+        Ok(SetVariableArguments::new(
+            self.check(reference)?.0,
+            name,
+            value,
+        ))
+    }
+
+    /// Builds arguments to evaluate `expression` in the scope of `frame`.
+    pub fn evaluate_arguments(
+        &self,
+        frame: &Suspended<FrameId>,
+        expression: String,
+        context: Option<EvaluateArgumentsContext>,
+    ) -> Result<EvaluateArguments, StaleReferenceError> {
+        let mut args = EvaluateArguments::new(expression).frame_id(self.check(frame)?.0);
+        args.context = context;
+        Ok(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::StoppedEventReason;
+
+    fn stopped(thread_id: i64, all_threads_stopped: bool) -> StoppedEvent {
+        StoppedEvent::new(StoppedEventReason::Breakpoint)
+            .thread_id(thread_id)
+            .all_threads_stopped(all_threads_stopped)
+    }
+
+    #[test]
+    fn test_references_expire_on_resume() {
+        let mut session = SessionEpoch::new();
+        assert!(session.tag(FrameId(1)).is_err());
+
+        let first = session.on_stopped(&stopped(1, true));
+        let frame = session.tag(FrameId(1)).unwrap();
+        assert_eq!(session.scopes_arguments(&frame).unwrap().frame_id, 1);
+
+        session.on_resumed();
+        let err = session.scopes_arguments(&frame).unwrap_err();
+        assert_eq!(err.reference_epoch, first);
+        assert_eq!(err.current_epoch, None);
+
+        let second = session.on_stopped(&stopped(1, true));
+        let err = session.scopes_arguments(&frame).unwrap_err();
+        assert_eq!(err.current_epoch, Some(second));
+
+        let frame = session.tag(FrameId(2)).unwrap();
+        let third = session.on_stopped(&stopped(1, true));
+        assert_ne!(third, second);
+        assert!(session.check(&frame).is_err());
+    }
+
+    #[test]
+    fn test_threads_stopping_in_a_row_share_an_epoch() {
+        let mut session = SessionEpoch::new();
+        let first = session.on_stopped(&stopped(1, false));
+        let frame = session.tag(FrameId(1)).unwrap();
+        assert_eq!(session.on_stopped(&stopped(2, false)), first);
+        assert_eq!(session.scopes_arguments(&frame).unwrap().frame_id, 1);
+
+        session.on_resumed();
+        assert_ne!(session.on_stopped(&stopped(2, false)), first);
+        assert!(session.check(&frame).is_err());
+    }
+}
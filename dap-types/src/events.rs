@@ -168,3 +168,40 @@ impl Event for Memory {
     const EVENT: &'static str = "memory";
     type Body = crate::MemoryEvent;
 }
+
+/// Receives the body type of an event whose name is only known at runtime, to check or coerce
+/// its body.
+pub(crate) trait EventVisitor {
+    type Output;
+
+    /// Called with the type the event body is checked as, and whether the protocol requires it.
+    fn visit<B: crate::strict::Strict + crate::lenient::Lenient>(
+        self,
+        body_required: bool,
+    ) -> Self::Output;
+}
+
+/// Calls `visitor` with the body type of the event `event`, or returns `None` if the protocol
+/// does not define it.
+pub(crate) fn visit_event<V: EventVisitor>(event: &str, visitor: V) -> Option<V::Output> {
+    Some(match event {
+        "initialized" => visitor.visit::<serde_json::Value>(false),
+        "stopped" => visitor.visit::<crate::StoppedEvent>(true),
+        "continued" => visitor.visit::<crate::ContinuedEvent>(true),
+        "exited" => visitor.visit::<crate::ExitedEvent>(true),
+        "terminated" => visitor.visit::<crate::TerminatedEvent>(false),
+        "thread" => visitor.visit::<crate::ThreadEvent>(true),
+        "output" => visitor.visit::<crate::OutputEvent>(true),
+        "breakpoint" => visitor.visit::<crate::BreakpointEvent>(true),
+        "module" => visitor.visit::<crate::ModuleEvent>(true),
+        "loadedSource" => visitor.visit::<crate::LoadedSourceEvent>(true),
+        "process" => visitor.visit::<crate::ProcessEvent>(true),
+        "capabilities" => visitor.visit::<crate::CapabilitiesEvent>(true),
+        "progressStart" => visitor.visit::<crate::ProgressStartEvent>(true),
+        "progressUpdate" => visitor.visit::<crate::ProgressUpdateEvent>(true),
+        "progressEnd" => visitor.visit::<crate::ProgressEndEvent>(true),
+        "invalidated" => visitor.visit::<crate::InvalidatedEvent>(true),
+        "memory" => visitor.visit::<crate::MemoryEvent>(true),
+        _ => return None,
+    })
+}
@@ -0,0 +1,294 @@
+use std::fmt;
+
+use crate::breakpoint_modes::validate_mode;
+use crate::{
+    BreakpointModeApplicability, Capabilities, ExceptionBreakMode, ExceptionBreakpointsFilter,
+    ExceptionFilterOptions, ExceptionOptions, ExceptionPathSegment,
+    SetExceptionBreakpointsArguments,
+};
+
+/// Error returned when an exception breakpoint configuration is not supported by the adapter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExceptionBreakpointsError {
+    /// The filter is not one of the adapter's `exceptionBreakpointFilters`.
+    UnknownFilter(String),
+    /// A condition was given for a filter that does not have `supportsCondition` set.
+    ConditionNotSupported(String),
+    /// A condition or mode was given, but the adapter does not support `filterOptions`.
+    FilterOptionsNotSupported,
+    /// The mode is not one of the adapter's `breakpointModes` applicable to exceptions.
+    UnknownMode(String),
+    /// Exception options were given, but the adapter does not support `exceptionOptions`.
+    ExceptionOptionsNotSupported,
+}
+
+impl fmt::Display for ExceptionBreakpointsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExceptionBreakpointsError::UnknownFilter(filter) => {
+                write!(f, "unknown exception filter `{filter}`")
+            }
+            ExceptionBreakpointsError::ConditionNotSupported(filter) => {
+                write!(f, "exception filter `{filter}` does not support conditions")
+            }
+            ExceptionBreakpointsError::FilterOptionsNotSupported => {
+                write!(f, "debug adapter does not support exception filter options")
+            }
+            ExceptionBreakpointsError::UnknownMode(mode) => {
+                write!(f, "unknown exception breakpoint mode `{mode}`")
+            }
+            ExceptionBreakpointsError::ExceptionOptionsNotSupported => {
+                write!(f, "debug adapter does not support exception options")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExceptionBreakpointsError {}
+
+/// Builds `SetExceptionBreakpointsArguments`, validating every choice against the capabilities
+/// advertised by the debug adapter.
+///
+/// Filters without a condition or mode are sent in `filters`, so they also work with adapters
+/// that do not support `filterOptions`.
+#[derive(Debug, Clone)]
+pub struct ExceptionBreakpointsBuilder<'a> {
+    capabilities: &'a Capabilities,
+    filters: Vec<String>,
+    filter_options: Vec<ExceptionFilterOptions>,
+    exception_options: Vec<ExceptionOptions>,
+}
+
+impl<'a> ExceptionBreakpointsBuilder<'a> {
+    pub fn new(capabilities: &'a Capabilities) -> Self {
+        Self {
+            capabilities,
+            filters: Vec::new(),
+            filter_options: Vec::new(),
+            exception_options: Vec::new(),
+        }
+    }
+
+    /// Creates a builder with the filters the adapter marks as enabled by `default`.
+    pub fn with_defaults(capabilities: &'a Capabilities) -> Self {
+        let mut builder = Self::new(capabilities);
+        builder.filters = builder
+            .available_filters()
+            .iter()
+            .filter(|filter| filter.default.unwrap_or(false))
+            .map(|filter| filter.filter.clone())
+            .collect();
+        builder
+    }
+
+    /// Exception filters advertised by the adapter.
+    pub fn available_filters(&self) -> &'a [ExceptionBreakpointsFilter] {
+        self.capabilities
+            .exception_breakpoint_filters
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    pub fn enable_filter(&mut self, filter: &str) -> Result<&mut Self, ExceptionBreakpointsError> {
+        self.find_filter(filter)?;
+        self.disable_filter(filter);
+        self.filters.push(filter.to_owned());
+        Ok(self)
+    }
+
+    /// Enables a filter with an optional condition and breakpoint mode.
+    pub fn enable_filter_with_options(
+        &mut self,
+        filter: &str,
+        condition: Option<String>,
+        mode: Option<String>,
+    ) -> Result<&mut Self, ExceptionBreakpointsError> {
+        if condition.is_none() && mode.is_none() {
+            return self.enable_filter(filter);
+        }
+        let descriptor = self.find_filter(filter)?;
+        if !self
+            .capabilities
+            .supports_exception_filter_options
+            .unwrap_or(false)
+        {
+            return Err(ExceptionBreakpointsError::FilterOptionsNotSupported);
+        }
+        if condition.is_some() && !descriptor.supports_condition.unwrap_or(false) {
+            return Err(ExceptionBreakpointsError::ConditionNotSupported(
+                filter.to_owned(),
+            ));
+        }
+        if let Some(mode) = &mode {
+            validate_mode(
+                self.capabilities,
+                &BreakpointModeApplicability::Exception,
+                mode,
+            )
+            .map_err(|err| ExceptionBreakpointsError::UnknownMode(err.mode))?;
+        }
+        self.disable_filter(filter);
+        let mut options = ExceptionFilterOptions::new(filter.to_owned());
+        options.condition = condition;
+        options.mode = mode;
+        self.filter_options.push(options);
+        Ok(self)
+    }
+
+    pub fn disable_filter(&mut self, filter: &str) -> &mut Self {
+        self.filters.retain(|f| f != filter);
+        self.filter_options.retain(|f| f.filter_id != filter);
+        self
+    }
+
+    pub fn add_exception_options(
+        &mut self,
+        options: ExceptionOptions,
+    ) -> Result<&mut Self, ExceptionBreakpointsError> {
+        if !self
+            .capabilities
+            .supports_exception_options
+            .unwrap_or(false)
+        {
+            return Err(ExceptionBreakpointsError::ExceptionOptionsNotSupported);
+        }
+        self.exception_options.push(options);
+        Ok(self)
+    }
+
+    pub fn build(&self) -> SetExceptionBreakpointsArguments {
+        let mut args = SetExceptionBreakpointsArguments::new(self.filters.clone());
+        args.filter_options =
+            (!self.filter_options.is_empty()).then(|| self.filter_options.clone());
+        args.exception_options =
+            (!self.exception_options.is_empty()).then(|| self.exception_options.clone());
+        args
+    }
+
+    fn find_filter(
+        &self,
+        filter: &str,
+    ) -> Result<&'a ExceptionBreakpointsFilter, ExceptionBreakpointsError> {
+        self.available_filters()
+            .iter()
+            .find(|f| f.filter == filter)
+            .ok_or_else(|| ExceptionBreakpointsError::UnknownFilter(filter.to_owned()))
+    }
+}
+
+impl ExceptionPathSegment {
+    /// Returns whether this segment selects an exception tree node with the given name.
+    pub fn matches(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name) != self.negate.unwrap_or(false)
+    }
+}
+
+impl ExceptionOptions {
+    /// Returns whether these options select the exception identified by `path`, where the first
+    /// element is the exception's category and the following ones descend the exception tree.
+    pub fn matches(&self, path: &[&str]) -> bool {
+        let segments = self.path.as_deref().unwrap_or_default();
+        segments.len() <= path.len()
+            && segments
+                .iter()
+                .zip(path)
+                .all(|(segment, name)| segment.matches(name))
+    }
+}
+
+/// Resolves the break mode for the exception identified by `path` (see
+/// [`ExceptionOptions::matches`]), or `None` if no options select it.
+///
+/// The protocol does not specify precedence between overlapping options, so this follows the
+/// common adapter behavior: the option with the longest matching path wins, and among options
+/// with equally long paths the one that comes last wins.
+pub fn effective_break_mode(
+    options: &[ExceptionOptions],
+    path: &[&str],
+) -> Option<ExceptionBreakMode> {
+    options
+        .iter()
+        .filter(|option| option.matches(path))
+        .max_by_key(|option| option.path.as_ref().map_or(0, Vec::len))
+        .map(|option| option.break_mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BreakpointMode;
+
+    #[test]
+    fn test_builder_validates_against_capabilities() {
+        let capabilities = Capabilities {
+            exception_breakpoint_filters: Some(vec![
+                ExceptionBreakpointsFilter::new("raised".to_owned(), "Raised".to_owned())
+                    .supports_condition(true),
+                ExceptionBreakpointsFilter::new("uncaught".to_owned(), "Uncaught".to_owned())
+                    .default(true),
+            ]),
+            supports_exception_filter_options: Some(true),
+            breakpoint_modes: Some(vec![BreakpointMode::new(
+                "hardware".to_owned(),
+                "Hardware".to_owned(),
+                vec![BreakpointModeApplicability::Source],
+            )]),
+            ..Default::default()
+        };
+        let mut builder = ExceptionBreakpointsBuilder::with_defaults(&capabilities);
+        builder
+            .enable_filter_with_options("raised", Some("e.code == 1".to_owned()), None)
+            .unwrap();
+        assert_eq!(
+            builder.enable_filter("unknown").unwrap_err(),
+            ExceptionBreakpointsError::UnknownFilter("unknown".to_owned())
+        );
+        assert_eq!(
+            builder
+                .enable_filter_with_options("uncaught", Some("x".to_owned()), None)
+                .unwrap_err(),
+            ExceptionBreakpointsError::ConditionNotSupported("uncaught".to_owned())
+        );
+        assert_eq!(
+            builder
+                .enable_filter_with_options("uncaught", None, Some("hardware".to_owned()))
+                .unwrap_err(),
+            ExceptionBreakpointsError::UnknownMode("hardware".to_owned())
+        );
+        assert_eq!(
+            builder
+                .add_exception_options(ExceptionOptions::new(ExceptionBreakMode::Always))
+                .unwrap_err(),
+            ExceptionBreakpointsError::ExceptionOptionsNotSupported
+        );
+
+        let args = builder.build();
+        assert_eq!(args.filters, vec!["uncaught".to_owned()]);
+        assert_eq!(args.filter_options.unwrap()[0].filter_id, "raised");
+        assert_eq!(args.exception_options, None);
+    }
+
+    #[test]
+    fn test_effective_break_mode() {
+        let segment = |negate: bool, names: &[&str]| {
+            ExceptionPathSegment::new(names.iter().map(|n| n.to_string()).collect()).negate(negate)
+        };
+        let options = vec![
+            ExceptionOptions::new(ExceptionBreakMode::Unhandled)
+                .path(vec![segment(false, &["Python Exceptions"])]),
+            ExceptionOptions::new(ExceptionBreakMode::Always).path(vec![
+                segment(false, &["Python Exceptions"]),
+                segment(true, &["StopIteration", "GeneratorExit"]),
+            ]),
+        ];
+        assert_eq!(
+            effective_break_mode(&options, &["Python Exceptions", "ValueError"]),
+            Some(ExceptionBreakMode::Always)
+        );
+        assert_eq!(
+            effective_break_mode(&options, &["Python Exceptions", "StopIteration"]),
+            Some(ExceptionBreakMode::Unhandled)
+        );
+        assert_eq!(effective_break_mode(&options, &["C++ Exceptions"]), None);
+    }
+}
@@ -0,0 +1,287 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use crate::{Capabilities, StoppedEvent};
+
+/// Comparison applied between a breakpoint's hit count and the value of a [`HitCondition`].
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum HitOperator {
+    /// `== n` (also written as `= n` or `=== n`): stop on exactly the n-th hit.
+    Equal,
+    /// `> n`: stop on every hit after the n-th one.
+    Greater,
+    /// `>= n`, or a bare `n`: stop on the n-th hit and every hit after it.
+    GreaterOrEqual,
+    /// `< n`: stop on every hit before the n-th one.
+    Less,
+    /// `<= n`: stop on every hit up to and including the n-th one.
+    LessOrEqual,
+    /// `% n`: stop on every n-th hit.
+    Modulo,
+}
+
+/// A parsed `hitCondition` of a `SourceBreakpoint`, `FunctionBreakpoint` or `DataBreakpoint`.
+///
+/// The protocol leaves the syntax of hit conditions up to the debug adapter. This follows the
+/// syntax used by most adapters: an optional operator (`==`, `=`, `>`, `>=`, `<`, `<=` or `%`)
+/// followed by a non-negative integer, with whitespace allowed around both.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub struct HitCondition {
+    pub operator: HitOperator,
+    pub value: u64,
+}
+
+impl HitCondition {
+    pub fn parse(input: &str) -> Result<HitCondition, HitConditionError> {
+        let trimmed = input.trim_start();
+        let start = input.len() - trimmed.len();
+        let operators = [
+            ("===", HitOperator::Equal),
+            ("==", HitOperator::Equal),
+            (">=", HitOperator::GreaterOrEqual),
+            ("<=", HitOperator::LessOrEqual),
+            ("=", HitOperator::Equal),
+            (">", HitOperator::Greater),
+            ("<", HitOperator::Less),
+            ("%", HitOperator::Modulo),
+        ];
+        let (operator, rest, offset) = operators
+            .iter()
+            .find_map(|(token, operator)| {
+                trimmed
+                    .strip_prefix(token)
+                    .map(|rest| (*operator, rest, start + token.len()))
+            })
+            .unwrap_or((HitOperator::GreaterOrEqual, trimmed, start));
+        let number = rest.trim();
+        let offset = offset + (rest.len() - rest.trim_start().len());
+        if number.is_empty() {
+            return Err(HitConditionError {
+                kind: HitConditionErrorKind::MissingValue,
+                offset,
+            });
+        }
+        if let Some(bad) = number.find(|c: char| !c.is_ascii_digit()) {
+            return Err(HitConditionError {
+                kind: HitConditionErrorKind::UnexpectedCharacter,
+                offset: offset + bad,
+            });
+        }
+        let value = number.parse::<u64>().map_err(|_| HitConditionError {
+            kind: HitConditionErrorKind::ValueOutOfRange,
+            offset,
+        })?;
+        if operator == HitOperator::Modulo && value == 0 {
+            return Err(HitConditionError {
+                kind: HitConditionErrorKind::ZeroModulus,
+                offset,
+            });
+        }
+        Ok(HitCondition { operator, value })
+    }
+
+    /// Returns whether a breakpoint that has been hit `hit_count` times (counting the current
+    /// hit, so the first hit is 1) should stop. A condition of `% 0`, which `parse` rejects, is
+    /// never met.
+    pub fn is_met(&self, hit_count: u64) -> bool {
+        match self.operator {
+            HitOperator::Equal => hit_count == self.value,
+            HitOperator::Greater => hit_count > self.value,
+            HitOperator::GreaterOrEqual => hit_count >= self.value,
+            HitOperator::Less => hit_count < self.value,
+            HitOperator::LessOrEqual => hit_count <= self.value,
+            HitOperator::Modulo => self.value != 0 && hit_count % self.value == 0,
+        }
+    }
+}
+
+impl FromStr for HitCondition {
+    type Err = HitConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HitCondition::parse(s)
+    }
+}
+
+impl fmt::Display for HitCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self.operator {
+            HitOperator::Equal => "==",
+            HitOperator::Greater => ">",
+            HitOperator::GreaterOrEqual => ">=",
+            HitOperator::Less => "<",
+            HitOperator::LessOrEqual => "<=",
+            HitOperator::Modulo => "%",
+        };
+        write!(f, "{operator} {}", self.value)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum HitConditionErrorKind {
+    /// The condition has no number after the operator.
+    MissingValue,
+    /// The condition contains something other than an operator and a number.
+    UnexpectedCharacter,
+    /// The number does not fit into a 64-bit hit count.
+    ValueOutOfRange,
+    /// The condition is `% 0`, which would never be met.
+    ZeroModulus,
+}
+
+/// Error returned when a hit condition cannot be parsed, suitable for displaying next to the
+/// input field it came from.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub struct HitConditionError {
+    pub kind: HitConditionErrorKind,
+    /// Byte offset into the input at which the problem was found.
+    pub offset: usize,
+}
+
+impl fmt::Display for HitConditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            HitConditionErrorKind::MissingValue => "expected a hit count",
+            HitConditionErrorKind::UnexpectedCharacter => {
+                "expected an operator (==, >, >=, <, <=, %) followed by a hit count"
+            }
+            HitConditionErrorKind::ValueOutOfRange => "hit count is too large",
+            HitConditionErrorKind::ZeroModulus => "modulus must be greater than zero",
+        };
+        write!(f, "{message} at offset {}", self.offset)
+    }
+}
+
+impl std::error::Error for HitConditionError {}
+
+/// What the client should do after a `stopped` event has been run through [`HitCounter`].
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum HitAction {
+    /// Present the stop to the user.
+    Stop,
+    /// None of the hit breakpoints has its condition met yet; the client should send a
+    /// `continue` request for the stopped thread without surfacing the stop.
+    Continue,
+}
+
+/// Client-side emulation of hit conditions for debug adapters that do not support them.
+///
+/// Breakpoints are registered by the `id` the adapter assigned to them, and their conditions
+/// are stripped from the arguments sent to the adapter. Each `stopped` event is then passed to
+/// [`HitCounter::on_stopped`], which counts hits and decides whether the stop should be shown.
+#[derive(Debug, Clone, Default)]
+pub struct HitCounter {
+    conditions: HashMap<i64, HitCondition>,
+    hits: HashMap<i64, u64>,
+}
+
+impl HitCounter {
+    /// Returns whether hit conditions have to be emulated by the client for an adapter with
+    /// the given capabilities.
+    pub fn is_needed(capabilities: &Capabilities) -> bool {
+        !capabilities
+            .supports_hit_conditional_breakpoints
+            .unwrap_or(false)
+    }
+
+    /// Sets or clears the condition for a breakpoint. The hit count of the breakpoint is kept,
+    /// matching how adapters treat edits of a breakpoint's hit condition.
+    pub fn set_condition(&mut self, breakpoint_id: i64, condition: Option<HitCondition>) {
+        match condition {
+            Some(condition) => {
+                self.conditions.insert(breakpoint_id, condition);
+            }
+            None => {
+                self.conditions.remove(&breakpoint_id);
+            }
+        }
+    }
+
+    /// Forgets a breakpoint that was removed, together with its hit count.
+    pub fn remove(&mut self, breakpoint_id: i64) {
+        self.conditions.remove(&breakpoint_id);
+        self.hits.remove(&breakpoint_id);
+    }
+
+    /// Resets all hit counts, e.g. when the debuggee is restarted.
+    pub fn reset(&mut self) {
+        self.hits.clear();
+    }
+
+    pub fn hit_count(&self, breakpoint_id: i64) -> u64 {
+        self.hits.get(&breakpoint_id).copied().unwrap_or(0)
+    }
+
+    /// Counts a hit for every breakpoint in `hitBreakpointIds` and decides whether to stop.
+    ///
+    /// The debuggee stays stopped if any hit breakpoint has no emulated condition or has its
+    /// condition met, and also for stops that were not caused by a known breakpoint.
+    pub fn on_stopped(&mut self, event: &StoppedEvent) -> HitAction {
+        let ids = match &event.hit_breakpoint_ids {
+            Some(ids) if !ids.is_empty() => ids,
+            _ => return HitAction::Stop,
+        };
+        let mut action = HitAction::Continue;
+        for id in ids {
+            let hits = self.hits.entry(*id).or_insert(0);
+            *hits += 1;
+            match self.conditions.get(id) {
+                Some(condition) if !condition.is_met(*hits) => {}
+                _ => action = HitAction::Stop,
+            }
+        }
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StoppedEventReason;
+
+    #[test]
+    fn test_hit_condition_parsing() {
+        let parse = |s: &str| HitCondition::parse(s).map(|c| (c.operator, c.value));
+        assert_eq!(parse("5"), Ok((HitOperator::GreaterOrEqual, 5)));
+        assert_eq!(parse(" >= 10 "), Ok((HitOperator::GreaterOrEqual, 10)));
+        assert_eq!(parse("==3"), Ok((HitOperator::Equal, 3)));
+        assert_eq!(parse("% 3"), Ok((HitOperator::Modulo, 3)));
+        assert_eq!(parse("<2"), Ok((HitOperator::Less, 2)));
+
+        let err = HitCondition::parse(">= 1x").unwrap_err();
+        assert_eq!(err.kind, HitConditionErrorKind::UnexpectedCharacter);
+        assert_eq!(err.offset, 4);
+        let err = HitCondition::parse("%").unwrap_err();
+        assert_eq!(err.kind, HitConditionErrorKind::MissingValue);
+        let err = HitCondition::parse("% 0").unwrap_err();
+        assert_eq!(err.kind, HitConditionErrorKind::ZeroModulus);
+
+        let zero_modulus = HitCondition {
+            operator: HitOperator::Modulo,
+            value: 0,
+        };
+        assert!(!zero_modulus.is_met(0));
+        assert!(!zero_modulus.is_met(3));
+    }
+
+    #[test]
+    fn test_hit_counter_emulation() {
+        let stopped = |ids: Vec<i64>| {
+            StoppedEvent::new(StoppedEventReason::Breakpoint)
+                .thread_id(1)
+                .hit_breakpoint_ids(ids)
+        };
+        let mut counter = HitCounter::default();
+        counter.set_condition(1, Some("% 3".parse().unwrap()));
+
+        assert_eq!(counter.on_stopped(&stopped(vec![1])), HitAction::Continue);
+        assert_eq!(counter.on_stopped(&stopped(vec![1])), HitAction::Continue);
+        assert_eq!(counter.on_stopped(&stopped(vec![1])), HitAction::Stop);
+        assert_eq!(counter.hit_count(1), 3);
+
+        // A breakpoint without a condition at the same location always stops.
+        assert_eq!(counter.on_stopped(&stopped(vec![1, 2])), HitAction::Stop);
+
+        counter.reset();
+        assert_eq!(counter.hit_count(1), 0);
+    }
+}
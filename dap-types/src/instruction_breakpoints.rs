@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+
+use crate::disassembly::{format_address, parse_address, InstructionRow};
+use crate::{
+    Breakpoint, BreakpointEvent, BreakpointEventReason, InstructionBreakpoint,
+    SetInstructionBreakpointsArguments, SetInstructionBreakpointsResponse,
+};
+
+/// Resolves the address of an instruction breakpoint: its `instructionReference` plus `offset`.
+/// Returns `None` if the reference is not an address or the result overflows.
+pub fn resolve_address(breakpoint: &InstructionBreakpoint) -> Option<u64> {
+    resolve(&breakpoint.instruction_reference, breakpoint.offset)
+}
+
+/// Resolves the address a breakpoint was set at, as reported by the adapter.
+pub fn breakpoint_address(breakpoint: &Breakpoint) -> Option<u64> {
+    resolve(
+        breakpoint.instruction_reference.as_deref()?,
+        breakpoint.offset,
+    )
+}
+
+fn resolve(reference: &str, offset: Option<i64>) -> Option<u64> {
+    parse_address(reference)?.checked_add_signed(offset.unwrap_or(0))
+}
+
+/// An instruction breakpoint known to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionBreakpointEntry {
+    /// The resolved address of the breakpoint.
+    pub address: u64,
+    pub breakpoint: InstructionBreakpoint,
+    /// The breakpoint as last reported by the adapter, or `None` if it has not been sent yet.
+    pub status: Option<Breakpoint>,
+}
+
+/// Maintains instruction breakpoints by resolved address.
+///
+/// Breakpoints are keyed by address rather than by `instructionReference`, so that the same
+/// instruction reached through different references (e.g. `0x1000` plus 4 and `0x1004`) is a
+/// single breakpoint. Every change has to be followed by a `setInstructionBreakpoints` request
+/// built with [`InstructionBreakpointManager::set_arguments`], which replaces the whole set.
+#[derive(Debug, Clone, Default)]
+pub struct InstructionBreakpointManager {
+    entries: BTreeMap<u64, InstructionBreakpointEntry>,
+}
+
+impl InstructionBreakpointManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, address: u64) -> Option<&InstructionBreakpointEntry> {
+        self.entries.get(&address)
+    }
+
+    /// Breakpoints ordered by address.
+    pub fn entries(&self) -> impl Iterator<Item = &InstructionBreakpointEntry> {
+        self.entries.values()
+    }
+
+    /// The breakpoint on a disassembly row, if any.
+    pub fn at_row(&self, row: &InstructionRow) -> Option<&InstructionBreakpointEntry> {
+        self.get(row.address)
+    }
+
+    /// Adds a breakpoint, replacing any breakpoint at the same address. The breakpoint is stored
+    /// with its resolved address as `instructionReference` and no `offset`. Returns the address,
+    /// or `None` if it could not be resolved.
+    pub fn add(&mut self, breakpoint: InstructionBreakpoint) -> Option<u64> {
+        let address = resolve_address(&breakpoint)?;
+        self.entries.insert(
+            address,
+            InstructionBreakpointEntry {
+                address,
+                breakpoint: InstructionBreakpoint {
+                    instruction_reference: format_address(address),
+                    offset: None,
+                    ..breakpoint
+                },
+                status: None,
+            },
+        );
+        Some(address)
+    }
+
+    pub fn remove(&mut self, address: u64) -> Option<InstructionBreakpointEntry> {
+        self.entries.remove(&address)
+    }
+
+    /// Sets a plain breakpoint at `address` if there is none, or removes the existing one.
+    /// Returns whether a breakpoint is set afterwards.
+    pub fn toggle(&mut self, address: u64) -> bool {
+        if self.entries.remove(&address).is_some() {
+            return false;
+        }
+        self.add(InstructionBreakpoint::new(format_address(address)));
+        true
+    }
+
+    /// Toggles the breakpoint on a disassembly row. Invalid placeholder rows are not real
+    /// instructions, so breakpoints can not be set on them; `None` is returned in that case.
+    pub fn toggle_row(&mut self, row: &InstructionRow) -> Option<bool> {
+        if row.invalid && !self.entries.contains_key(&row.address) {
+            return None;
+        }
+        Some(self.toggle(row.address))
+    }
+
+    /// Arguments for a `setInstructionBreakpoints` request sending the full set.
+    pub fn set_arguments(&self) -> SetInstructionBreakpointsArguments {
+        SetInstructionBreakpointsArguments::new(
+            self.entries
+                .values()
+                .map(|entry| entry.breakpoint.clone())
+                .collect(),
+        )
+    }
+
+    /// Records the breakpoints returned for the request built by
+    /// [`InstructionBreakpointManager::set_arguments`], which correspond to the entries by
+    /// position.
+    ///
+    /// If the adapter reports that a breakpoint was set at a different address (e.g. moved to
+    /// the start of an instruction), the entry is moved to that address, unless another
+    /// breakpoint is already there.
+    pub fn on_set_response(&mut self, response: &SetInstructionBreakpointsResponse) {
+        let addresses = self.entries.keys().copied().collect::<Vec<_>>();
+        for (address, breakpoint) in addresses.into_iter().zip(&response.breakpoints) {
+            self.update(address, breakpoint.clone());
+        }
+    }
+
+    /// Applies a `breakpoint` event to the entry with the same breakpoint `id`. Returns whether
+    /// an entry was updated.
+    pub fn on_breakpoint_event(&mut self, event: &BreakpointEvent) -> bool {
+        let Some(id) = event.breakpoint.id else {
+            return false;
+        };
+        let Some(address) = self
+            .entries
+            .values()
+            .find(|entry| entry.status.as_ref().and_then(|s| s.id) == Some(id))
+            .map(|entry| entry.address)
+        else {
+            return false;
+        };
+        match event.reason {
+            BreakpointEventReason::Removed => {
+                if let Some(entry) = self.entries.get_mut(&address) {
+                    entry.status = None;
+                }
+            }
+            _ => self.update(address, event.breakpoint.clone()),
+        }
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn update(&mut self, address: u64, status: Breakpoint) {
+        let new_address = breakpoint_address(&status)
+            .filter(|new| *new == address || !self.entries.contains_key(new));
+        let Some(mut entry) = self.entries.remove(&address) else {
+            return;
+        };
+        if let Some(new_address) = new_address {
+            entry.address = new_address;
+            entry.breakpoint.instruction_reference = format_address(new_address);
+        }
+        entry.status = Some(status);
+        self.entries.insert(entry.address, entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakpoints_keyed_by_address() {
+        let mut manager = InstructionBreakpointManager::new();
+        let address = manager.add(
+            InstructionBreakpoint::new("0x1010".to_owned())
+                .offset(-8)
+                .condition("x > 1".to_owned()),
+        );
+        assert_eq!(address, Some(0x1008));
+        assert!(!manager.toggle(0x1008));
+        assert!(manager.toggle(0x1008));
+        assert!(manager.toggle(0x1000));
+
+        let args = manager.set_arguments();
+        let references = args
+            .breakpoints
+            .iter()
+            .map(|b| b.instruction_reference.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(references, vec!["0x1000", "0x1008"]);
+
+        manager.on_set_response(&SetInstructionBreakpointsResponse::new(vec![
+            Breakpoint::new(true)
+                .id(1)
+                .instruction_reference("0x1000".to_owned())
+                .offset(2),
+            Breakpoint::new(true)
+                .id(2)
+                .instruction_reference("0x1008".to_owned()),
+        ]));
+        assert!(manager.get(0x1000).is_none());
+        assert_eq!(
+            manager.get(0x1002).unwrap().status.as_ref().unwrap().id,
+            Some(1)
+        );
+
+        assert!(manager.on_breakpoint_event(&BreakpointEvent::new(
+            BreakpointEventReason::Changed,
+            Breakpoint::new(true)
+                .id(2)
+                .instruction_reference("4104".to_owned())
+                .offset(4),
+        )));
+        assert_eq!(
+            manager.entries().map(|e| e.address).collect::<Vec<_>>(),
+            vec![0x1002, 0x100c]
+        );
+    }
+}
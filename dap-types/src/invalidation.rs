@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{InvalidatedAreas, InvalidatedEvent};
+
+/// Description of the data a client has cached, used to route an `invalidated` event.
+///
+/// Only the relationships between the cached entries are needed, not the data itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CachedState {
+    /// Whether the result of a `threads` request is cached.
+    pub threads: bool,
+    /// Cached `stackTrace` results: thread id to the ids of the frames in the stack trace.
+    pub stack_traces: BTreeMap<i64, Vec<i64>>,
+    /// Cached `scopes` results: frame id to the `variablesReference`s of its scopes.
+    pub scopes: BTreeMap<i64, Vec<i64>>,
+    /// Cached `variables` results: `variablesReference` to the non-zero `variablesReference`s
+    /// of the returned variables, so that nested variables can be found.
+    pub variables: BTreeMap<i64, Vec<i64>>,
+}
+
+/// A request to send to refresh cached data after an `invalidated` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Refetch {
+    Threads,
+    StackTrace { thread_id: i64 },
+    Scopes { frame_id: i64 },
+    Variables { variables_reference: i64 },
+}
+
+/// The cached entries invalidated by an `invalidated` event. Each invalidated entry has to be
+/// dropped from the cache and, if it is still shown, refetched with [`InvalidationPlan::refetches`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvalidationPlan {
+    pub threads: bool,
+    /// Threads whose cached stack trace is invalid.
+    pub stack_traces: BTreeSet<i64>,
+    /// Frames whose cached scopes are invalid.
+    pub scopes: BTreeSet<i64>,
+    /// `variablesReference`s whose cached variables are invalid.
+    pub variables: BTreeSet<i64>,
+}
+
+impl InvalidationPlan {
+    pub fn is_empty(&self) -> bool {
+        !self.threads
+            && self.stack_traces.is_empty()
+            && self.scopes.is_empty()
+            && self.variables.is_empty()
+    }
+
+    /// Requests that refetch the invalidated entries, ordered so that parents are fetched
+    /// before their children.
+    pub fn refetches(&self) -> Vec<Refetch> {
+        let threads = self.threads.then_some(Refetch::Threads);
+        let stack_traces = self
+            .stack_traces
+            .iter()
+            .map(|&thread_id| Refetch::StackTrace { thread_id });
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|&frame_id| Refetch::Scopes { frame_id });
+        let variables = self
+            .variables
+            .iter()
+            .map(|&variables_reference| Refetch::Variables {
+                variables_reference,
+            });
+        threads
+            .into_iter()
+            .chain(stack_traces)
+            .chain(scopes)
+            .chain(variables)
+            .collect()
+    }
+}
+
+/// Returns the areas of the event, applying the protocol's fallback: if `areas` is missing,
+/// empty, or contains a value that is not understood, a single `all` is assumed.
+pub fn effective_areas(event: &InvalidatedEvent) -> Vec<InvalidatedAreas> {
+    match &event.areas {
+        Some(areas)
+            if !areas.is_empty()
+                && !areas
+                    .iter()
+                    .any(|a| matches!(a, InvalidatedAreas::Other(_))) =>
+        {
+            areas.clone()
+        }
+        _ => vec![InvalidatedAreas::All],
+    }
+}
+
+/// Computes which cached entries an `invalidated` event affects.
+///
+/// The event is scoped to `stackFrameId` if present (in which case `threadId` is ignored),
+/// otherwise to `threadId` if present, otherwise to all data. Invalidating an entry also
+/// invalidates the cached entries below it, since their references may no longer be valid:
+/// - `threads` invalidates the thread list;
+/// - `stacks` invalidates stack traces and the scopes and variables of their frames;
+/// - `variables` invalidates scopes and all variables beneath them;
+/// - `all` invalidates everything in scope.
+///
+/// A frame scoped event invalidates the stack trace of the thread containing the frame, but
+/// only the scopes and variables of the frame itself.
+pub fn route(event: &InvalidatedEvent, cache: &CachedState) -> InvalidationPlan {
+    let areas = effective_areas(event);
+    let all = areas.contains(&InvalidatedAreas::All);
+    let threads_area = all || areas.contains(&InvalidatedAreas::Threads);
+    let stacks_area = all || areas.contains(&InvalidatedAreas::Stacks);
+    let variables_area = stacks_area || areas.contains(&InvalidatedAreas::Variables);
+
+    let (threads, frames): (Vec<i64>, Vec<i64>) = if let Some(frame_id) = event.stack_frame_id {
+        let owners = cache
+            .stack_traces
+            .iter()
+            .filter(|(_, frames)| frames.contains(&frame_id))
+            .map(|(thread_id, _)| *thread_id)
+            .collect();
+        (owners, vec![frame_id])
+    } else if let Some(thread_id) = event.thread_id {
+        let frames = cache
+            .stack_traces
+            .get(&thread_id)
+            .cloned()
+            .unwrap_or_default();
+        (vec![thread_id], frames)
+    } else {
+        (
+            cache.stack_traces.keys().copied().collect(),
+            cache.scopes.keys().copied().collect(),
+        )
+    };
+
+    let mut plan = InvalidationPlan {
+        threads: threads_area && cache.threads && event.stack_frame_id.is_none(),
+        ..Default::default()
+    };
+    if stacks_area {
+        plan.stack_traces = threads
+            .into_iter()
+            .filter(|thread_id| cache.stack_traces.contains_key(thread_id))
+            .collect();
+    }
+    if variables_area {
+        plan.scopes = frames
+            .into_iter()
+            .filter(|frame_id| cache.scopes.contains_key(frame_id))
+            .collect();
+        if event.stack_frame_id.is_none() && event.thread_id.is_none() {
+            // Unscoped: also covers variables not reachable from a frame, such as those of
+            // evaluate results or structured output.
+            plan.variables = cache.variables.keys().copied().collect();
+        } else {
+            let mut pending = plan
+                .scopes
+                .iter()
+                .flat_map(|frame_id| cache.scopes[frame_id].iter().copied())
+                .collect::<Vec<_>>();
+            while let Some(reference) = pending.pop() {
+                if let Some(children) = cache.variables.get(&reference) {
+                    if plan.variables.insert(reference) {
+                        pending.extend(children);
+                    }
+                }
+            }
+        }
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> CachedState {
+        CachedState {
+            threads: true,
+            stack_traces: BTreeMap::from([(1, vec![10, 11]), (2, vec![20])]),
+            scopes: BTreeMap::from([(10, vec![100]), (11, vec![110]), (20, vec![200])]),
+            variables: BTreeMap::from([
+                (100, vec![101]),
+                (101, vec![]),
+                (110, vec![]),
+                (200, vec![]),
+                (300, vec![]),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_unknown_areas_fall_back_to_all() {
+        let event = InvalidatedEvent {
+            areas: Some(vec![
+                InvalidatedAreas::Variables,
+                InvalidatedAreas::Other("registers".to_owned()),
+            ]),
+            ..Default::default()
+        };
+        let plan = route(&event, &cache());
+        assert!(plan.threads);
+        assert_eq!(plan.stack_traces, BTreeSet::from([1, 2]));
+        assert_eq!(plan.variables.len(), 5);
+        assert_eq!(plan.refetches()[0], Refetch::Threads);
+    }
+
+    #[test]
+    fn test_scoped_invalidation() {
+        let event = InvalidatedEvent::new()
+            .areas(vec![InvalidatedAreas::Variables])
+            .thread_id(2)
+            .stack_frame_id(10);
+        let plan = route(&event, &cache());
+        assert!(!plan.threads);
+        assert!(plan.stack_traces.is_empty());
+        assert_eq!(plan.scopes, BTreeSet::from([10]));
+        assert_eq!(plan.variables, BTreeSet::from([100, 101]));
+
+        let event = InvalidatedEvent {
+            areas: Some(vec![InvalidatedAreas::Stacks]),
+            thread_id: Some(1),
+            ..Default::default()
+        };
+        let plan = route(&event, &cache());
+        assert_eq!(plan.stack_traces, BTreeSet::from([1]));
+        assert_eq!(plan.scopes, BTreeSet::from([10, 11]));
+        assert_eq!(plan.variables, BTreeSet::from([100, 101, 110]));
+    }
+}
@@ -0,0 +1,544 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::events::{visit_event, EventVisitor};
+use crate::messages::Message;
+use crate::requests::{visit_command, CommandVisitor};
+use crate::strict::Strict;
+use crate::ErrorResponse;
+
+/// A type that can repair JSON from adapters that do not follow the protocol, so that it
+/// deserializes.
+///
+/// The lenient profile applies these coercions, each reported as a [`Coercion`]:
+/// - a string holding a number or boolean, where one is expected, is parsed;
+/// - `null` for an optional property is removed, so that it becomes `None`;
+/// - a missing or `null` required property is replaced with a default, if its type has one.
+///   Numbers default to 0, strings and open enums to `""`, arrays to `[]`, and objects to an
+///   object with the defaults of their required properties.
+///
+/// A negative integer, where the protocol expects an unsigned one, is left as it is and reported
+/// as [`CoercionKind::NegativeInteger`]. Id and reference fields, like `threadId` and
+/// `variablesReference`, are generated as `i64` because some adapters send negative values, so
+/// such messages deserialize. In other fields, like `line`, a negative integer fails to
+/// deserialize, since no unsigned value has the same meaning.
+pub trait Lenient {
+    /// Rewrites `value`, found at `path`, so that it deserializes as `Self`, calling `on_coercion`
+    /// for every change.
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion));
+
+    /// The value to use for a missing required property of this type, if there is one.
+    fn default_value() -> Option<Value> {
+        None
+    }
+}
+
+/// A change made to a message by the lenient profile, or a value that does not follow the
+/// protocol but is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Coercion {
+    /// The JSON path of the changed value, like `$.breakpoints[0].line`.
+    pub path: String,
+    pub kind: CoercionKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoercionKind {
+    /// A number was parsed from a string.
+    NumberFromString,
+    /// A boolean was parsed from a string.
+    BoolFromString,
+    /// A negative integer was found where the protocol expects an unsigned one. It is left
+    /// unchanged.
+    NegativeInteger,
+    /// An optional property set to `null` was removed.
+    NullRemoved,
+    /// A missing or `null` required property was set to a default.
+    DefaultInserted,
+}
+
+impl fmt::Display for Coercion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let change = match self.kind {
+            CoercionKind::NumberFromString => "parsed number from string",
+            CoercionKind::BoolFromString => "parsed boolean from string",
+            CoercionKind::NegativeInteger => "kept negative integer where unsigned is expected",
+            CoercionKind::NullRemoved => "removed null optional property",
+            CoercionKind::DefaultInserted => "inserted default for missing required property",
+        };
+        write!(f, "{}: {change}", self.path)
+    }
+}
+
+/// Deserializes `value` with the lenient profile, calling `on_coercion` for every change made to
+/// it. Pass `|_| {}` to ignore the changes.
+///
+/// ```
+/// # use dap_types::{lenient, Thread};
+/// let mut coercions = Vec::new();
+/// let thread: Thread = lenient::from_value(serde_json::json!({ "id": "7", "name": null }), |c| {
+///     coercions.push(c.to_string())
+/// })
+/// .unwrap();
+/// assert_eq!(thread.id, 7);
+/// assert_eq!(coercions.len(), 2);
+/// ```
+pub fn from_value<T: Lenient + DeserializeOwned>(
+    mut value: Value,
+    mut on_coercion: impl FnMut(&Coercion),
+) -> serde_json::Result<T> {
+    T::coerce(&mut value, "$", &mut on_coercion);
+    serde_json::from_value(value)
+}
+
+/// Deserializes a message with the lenient profile, calling `on_coercion` for every change made
+/// to it.
+///
+/// The arguments of requests, the bodies of responses and the bodies of events are coerced
+/// according to their type, which depends on the command or event. A missing payload that the
+/// protocol requires is inserted.
+///
+/// ```
+/// # use dap_types::lenient;
+/// let message = serde_json::json!({
+///     "seq": 2,
+///     "type": "response",
+///     "request_seq": 1,
+///     "success": true,
+///     "command": "threads",
+///     "body": { "threads": [{ "id": "1", "name": "main" }] }
+/// });
+/// let mut coercions = Vec::new();
+/// lenient::message_from_value(message, |c| coercions.push(c.to_string())).unwrap();
+/// assert_eq!(coercions, vec!["$.body.threads[0].id: parsed number from string"]);
+/// ```
+pub fn message_from_value(
+    mut value: Value,
+    mut on_coercion: impl FnMut(&Coercion),
+) -> serde_json::Result<Message> {
+    let name = |field| value.get(field).and_then(Value::as_str).map(str::to_owned);
+    match value.get("type").and_then(Value::as_str) {
+        Some("request") => {
+            if let Some(command) = name("command") {
+                let coercer = PayloadCoercer {
+                    message: &mut value,
+                    field: "arguments",
+                    on_coercion: &mut on_coercion,
+                };
+                visit_command(&command, coercer);
+            }
+        }
+        Some("response") => {
+            let success = value.get("success").and_then(Value::as_bool);
+            if let Some(command) = name("command") {
+                let coercer = PayloadCoercer {
+                    message: &mut value,
+                    field: "body",
+                    on_coercion: &mut on_coercion,
+                };
+                match success {
+                    Some(true) => {
+                        visit_command(&command, coercer);
+                    }
+                    // Adapters commonly leave out the body of error responses, which the
+                    // protocol requires. It has no required properties, so none is inserted.
+                    Some(false) => coercer.coerce::<ErrorResponse>(false),
+                    None => {}
+                }
+            }
+        }
+        Some("event") => {
+            if let Some(event) = name("event") {
+                let coercer = PayloadCoercer {
+                    message: &mut value,
+                    field: "body",
+                    on_coercion: &mut on_coercion,
+                };
+                visit_event(&event, coercer);
+            }
+        }
+        _ => {}
+    }
+    serde_json::from_value(value)
+}
+
+/// Coerces the `arguments` of a request or the `body` of a response or event as `T`.
+struct PayloadCoercer<'a> {
+    message: &'a mut Value,
+    field: &'static str,
+    on_coercion: &'a mut dyn FnMut(&Coercion),
+}
+
+impl PayloadCoercer<'_> {
+    fn coerce<T: Lenient>(self, required: bool) {
+        let Some(message) = self.message.as_object_mut() else {
+            return;
+        };
+        let path = crate::strict::field_path("$", self.field);
+        if let Some(payload) = message.get_mut(self.field).filter(|p| !p.is_null()) {
+            T::coerce(payload, &path, self.on_coercion);
+        } else if let Some(default) = T::default_value().filter(|d| required && !d.is_null()) {
+            message.insert(self.field.to_owned(), default);
+            (self.on_coercion)(&Coercion {
+                path,
+                kind: CoercionKind::DefaultInserted,
+            });
+        }
+    }
+}
+
+impl CommandVisitor for PayloadCoercer<'_> {
+    type Output = ();
+
+    fn visit<A, B>(self, arguments_required: bool, body_required: bool)
+    where
+        A: Strict + Lenient,
+        B: Strict + Lenient,
+    {
+        if self.field == "arguments" {
+            self.coerce::<A>(arguments_required);
+        } else {
+            self.coerce::<B>(body_required);
+        }
+    }
+}
+
+impl EventVisitor for PayloadCoercer<'_> {
+    type Output = ();
+
+    fn visit<B: Strict + Lenient>(self, body_required: bool) {
+        self.coerce::<B>(body_required);
+    }
+}
+
+#[doc(hidden)]
+pub fn coerce_field<T: Lenient>(
+    object: &mut serde_json::Map<String, Value>,
+    name: &str,
+    required: bool,
+    path: &str,
+    on_coercion: &mut dyn FnMut(&Coercion),
+) {
+    let path = crate::strict::field_path(path, name);
+    let kind = match object.get(name) {
+        Some(Value::Null) if !required => {
+            object.remove(name);
+            CoercionKind::NullRemoved
+        }
+        Some(Value::Null) | None if required => match T::default_value() {
+            // Nullable properties have a `null` default, and are valid as they are.
+            Some(default) if !default.is_null() => {
+                object.insert(name.to_owned(), default);
+                CoercionKind::DefaultInserted
+            }
+            _ => return,
+        },
+        _ => {
+            if let Some(value) = object.get_mut(name) {
+                T::coerce(value, &path, on_coercion);
+            }
+            return;
+        }
+    };
+    on_coercion(&Coercion { path, kind });
+}
+
+/// Implements [`Lenient`] for a struct, given the protocol names and types of its fields, or for
+/// a type that is not coerced and has no default. Used by the generated types.
+macro_rules! impl_lenient {
+    (
+        $ty:ident {
+            required { $($required:literal: $required_ty:ty,)* }
+            optional { $($optional:literal: $optional_ty:ty,)* }
+        }
+    ) => {
+        impl $crate::lenient::Lenient for $ty {
+            // Structs without fields use none of the arguments.
+            #[allow(unused_variables)]
+            fn coerce(
+                value: &mut serde_json::Value,
+                path: &str,
+                on_coercion: &mut dyn FnMut(&$crate::lenient::Coercion),
+            ) {
+                let Some(object) = value.as_object_mut() else {
+                    return;
+                };
+                $(
+                    $crate::lenient::coerce_field::<$required_ty>(
+                        object,
+                        $required,
+                        true,
+                        path,
+                        on_coercion,
+                    );
+                )*
+                $(
+                    $crate::lenient::coerce_field::<$optional_ty>(
+                        object,
+                        $optional,
+                        false,
+                        path,
+                        on_coercion,
+                    );
+                )*
+            }
+
+            fn default_value() -> Option<serde_json::Value> {
+                Some(serde_json::Value::Object(serde_json::Map::from_iter([$(
+                    (
+                        $required.to_owned(),
+                        <$required_ty as $crate::lenient::Lenient>::default_value()?,
+                    ),
+                )*])))
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl $crate::lenient::Lenient for $ty {
+            fn coerce(
+                _value: &mut serde_json::Value,
+                _path: &str,
+                _on_coercion: &mut dyn FnMut(&$crate::lenient::Coercion),
+            ) {
+            }
+        }
+    };
+}
+
+pub(crate) use impl_lenient;
+
+fn coerce_integer<T: FromStr + Into<Value>>(
+    value: &mut Value,
+    path: &str,
+    on_coercion: &mut dyn FnMut(&Coercion),
+    unsigned: bool,
+) {
+    let mut report = |kind| {
+        on_coercion(&Coercion {
+            path: path.to_owned(),
+            kind,
+        })
+    };
+    if let Value::String(s) = &*value {
+        let s = s.trim();
+        // Negative numbers are parsed for unsigned types too, and reported below.
+        *value = match (s.parse::<T>(), s.parse::<i64>()) {
+            (Ok(n), _) => n.into(),
+            (Err(_), Ok(n)) => n.into(),
+            (Err(_), Err(_)) => return,
+        };
+        report(CoercionKind::NumberFromString);
+    }
+    if unsigned && value.as_i64().is_some_and(|n| n < 0) {
+        report(CoercionKind::NegativeInteger);
+    }
+}
+
+impl Lenient for u32 {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        coerce_integer::<u32>(value, path, on_coercion, true);
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(0.into())
+    }
+}
+
+impl Lenient for u64 {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        coerce_integer::<u64>(value, path, on_coercion, true);
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(0.into())
+    }
+}
+
+impl Lenient for i64 {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        coerce_integer::<i64>(value, path, on_coercion, false);
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(0.into())
+    }
+}
+
+impl Lenient for bool {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        let Some(parsed) = value.as_str().and_then(|s| s.trim().parse::<bool>().ok()) else {
+            return;
+        };
+        *value = parsed.into();
+        on_coercion(&Coercion {
+            path: path.to_owned(),
+            kind: CoercionKind::BoolFromString,
+        });
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(false.into())
+    }
+}
+
+impl Lenient for String {
+    fn coerce(_value: &mut Value, _path: &str, _on_coercion: &mut dyn FnMut(&Coercion)) {}
+
+    fn default_value() -> Option<Value> {
+        Some("".into())
+    }
+}
+
+impl Lenient for Value {
+    fn coerce(_value: &mut Value, _path: &str, _on_coercion: &mut dyn FnMut(&Coercion)) {}
+
+    fn default_value() -> Option<Value> {
+        Some(Value::Null)
+    }
+}
+
+impl<T: Lenient> Lenient for Option<T> {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        if !value.is_null() {
+            T::coerce(value, path, on_coercion);
+        }
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(Value::Null)
+    }
+}
+
+impl<T: Lenient> Lenient for Vec<T> {
+    fn coerce(value: &mut Value, path: &str, on_coercion: &mut dyn FnMut(&Coercion)) {
+        if let Some(items) = value.as_array_mut() {
+            for (i, item) in items.iter_mut().enumerate() {
+                T::coerce(item, &format!("{path}[{i}]"), on_coercion);
+            }
+        }
+    }
+
+    fn default_value() -> Option<Value> {
+        Some(Value::Array(Vec::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::Events;
+    use crate::{Scope, StackFrame, StackTraceResponse, StoppedEventReason, Thread};
+    use serde_json::json;
+
+    #[test]
+    fn test_coercions_are_reported() {
+        let response = json!({
+            "stackFrames": [
+                { "id": "1", "name": "main", "line": "3", "column": 0, "source": null }
+            ],
+            "totalFrames": "1"
+        });
+        assert!(serde_json::from_value::<StackTraceResponse>(response.clone()).is_err());
+        let mut coercions = Vec::new();
+        let response: StackTraceResponse =
+            from_value(response, |c| coercions.push(c.clone())).unwrap();
+        assert_eq!(response.stack_frames[0].id, 1);
+        assert_eq!(response.stack_frames[0].line, 3);
+        assert_eq!(response.stack_frames[0].source, None);
+        assert_eq!(response.total_frames, Some(1));
+        assert_eq!(
+            coercions.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+            vec![
+                "$.stackFrames[0].id: parsed number from string",
+                "$.stackFrames[0].line: parsed number from string",
+                "$.stackFrames[0].source: removed null optional property",
+                "$.totalFrames: parsed number from string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_event_body() {
+        let message = json!({ "seq": 1, "type": "event", "event": "stopped" });
+        let mut coercions = Vec::new();
+        let Message::Event(event) =
+            message_from_value(message, |c| coercions.push(c.clone())).unwrap()
+        else {
+            panic!("expected an event");
+        };
+        let Events::Stopped(stopped) = *event else {
+            panic!("expected a stopped event");
+        };
+        assert_eq!(stopped.reason, StoppedEventReason::Other(String::new()));
+        assert_eq!(
+            coercions,
+            vec![Coercion {
+                path: "$.body".to_owned(),
+                kind: CoercionKind::DefaultInserted,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_negative_ids_are_kept() {
+        let scope = json!({ "name": "Locals", "variablesReference": -1, "expensive": false });
+        let mut coercions = Vec::new();
+        let scope: Scope = from_value(scope, |c| coercions.push(c.to_string())).unwrap();
+        assert_eq!(scope.variables_reference, -1);
+        let thread = json!({ "id": "-1", "name": "main" });
+        let thread: Thread = from_value(thread, |c| coercions.push(c.to_string())).unwrap();
+        assert_eq!(thread.id, -1);
+        assert_eq!(
+            coercions,
+            vec![
+                "$.variablesReference: kept negative integer where unsigned is expected",
+                "$.id: parsed number from string",
+                "$.id: kept negative integer where unsigned is expected",
+            ]
+        );
+
+        let frame = json!({ "id": 1, "name": "main", "line": -1, "column": 0 });
+        assert!(from_value::<StackFrame>(frame, |_| {}).is_err());
+    }
+
+    #[test]
+    fn test_response_body() {
+        let message = json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": true,
+            "command": "stackTrace"
+        });
+        let mut coercions = Vec::new();
+        let Message::Response(response) =
+            message_from_value(message, |c| coercions.push(c.to_string())).unwrap()
+        else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.body, Some(json!({ "stackFrames": [] })));
+        assert_eq!(
+            coercions,
+            vec!["$.body: inserted default for missing required property"]
+        );
+
+        let message = json!({
+            "seq": 3,
+            "type": "response",
+            "request_seq": 2,
+            "success": false,
+            "command": "evaluate",
+            "message": "failed",
+            "body": { "error": { "id": "4", "format": "no such variable" } }
+        });
+        let mut coercions = Vec::new();
+        message_from_value(message, |c| coercions.push(c.to_string())).unwrap();
+        assert_eq!(
+            coercions,
+            vec!["$.body.error.id: parsed number from string"]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use crate::{LoadedSourceEvent, LoadedSourceEventReason, LoadedSourcesResponse, Source};
+
+/// Identity of a `Source`: its `sourceReference` if it has one (a value > 0), otherwise its
+/// `path`, otherwise its `name`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SourceKey {
+    Reference(i64),
+    Path(String),
+    Name(String),
+}
+
+impl SourceKey {
+    pub fn of(source: &Source) -> Option<SourceKey> {
+        if let Some(reference) = source.source_reference.filter(|r| *r > 0) {
+            Some(SourceKey::Reference(reference))
+        } else if let Some(path) = &source.path {
+            Some(SourceKey::Path(path.clone()))
+        } else {
+            source.name.clone().map(SourceKey::Name)
+        }
+    }
+}
+
+/// The set of sources loaded by the debuggee, kept up to date with `loadedSource` events and
+/// `loadedSources` responses.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedSourceRegistry {
+    sources: HashMap<SourceKey, Source>,
+    /// Keys in the order the sources were first seen.
+    order: Vec<SourceKey>,
+}
+
+impl LoadedSourceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &SourceKey) -> Option<&Source> {
+        self.sources.get(key)
+    }
+
+    /// Sources in the order they were first reported.
+    pub fn sources(&self) -> impl Iterator<Item = &Source> {
+        self.order.iter().map(|key| &self.sources[key])
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Applies the event. Sources without a reference, path or name can not be identified and
+    /// are ignored.
+    pub fn on_loaded_source_event(&mut self, event: &LoadedSourceEvent) {
+        let Some(key) = SourceKey::of(&event.source) else {
+            return;
+        };
+        match event.reason {
+            LoadedSourceEventReason::New | LoadedSourceEventReason::Changed => {
+                self.insert(key, event.source.clone())
+            }
+            LoadedSourceEventReason::Removed => {
+                if self.sources.remove(&key).is_some() {
+                    self.order.retain(|k| k != &key);
+                }
+            }
+        }
+    }
+
+    /// Replaces all sources with the ones in the response.
+    pub fn on_loaded_sources_response(&mut self, response: &LoadedSourcesResponse) {
+        self.clear();
+        for source in &response.sources {
+            if let Some(key) = SourceKey::of(source) {
+                self.insert(key, source.clone());
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.sources.clear();
+        self.order.clear();
+    }
+
+    fn insert(&mut self, key: SourceKey, source: Source) {
+        if !self.sources.contains_key(&key) {
+            self.order.push(key.clone());
+        }
+        self.sources.insert(key, source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_key_fallback() {
+        let source = Source::new()
+            .name("main.rs".to_owned())
+            .path("/src/main.rs".to_owned())
+            .source_reference(4);
+        assert_eq!(SourceKey::of(&source), Some(SourceKey::Reference(4)));
+        let source = source.source_reference(0);
+        assert_eq!(
+            SourceKey::of(&source),
+            Some(SourceKey::Path("/src/main.rs".to_owned()))
+        );
+        let source = Source::new().name("<eval>".to_owned());
+        assert_eq!(
+            SourceKey::of(&source),
+            Some(SourceKey::Name("<eval>".to_owned()))
+        );
+        assert_eq!(SourceKey::of(&Source::new()), None);
+    }
+
+    #[test]
+    fn test_loaded_source_events() {
+        let mut registry = LoadedSourceRegistry::new();
+        let a = Source::new().path("/a.rs".to_owned());
+        let b = Source::new().name("b".to_owned()).source_reference(1);
+        registry.on_loaded_source_event(&LoadedSourceEvent::new(
+            LoadedSourceEventReason::New,
+            a.clone(),
+        ));
+        registry.on_loaded_source_event(&LoadedSourceEvent::new(
+            LoadedSourceEventReason::New,
+            b.clone(),
+        ));
+        registry.on_loaded_source_event(&LoadedSourceEvent::new(
+            LoadedSourceEventReason::Changed,
+            b.clone().name("b2".to_owned()),
+        ));
+        registry.on_loaded_source_event(&LoadedSourceEvent::new(
+            LoadedSourceEventReason::New,
+            Source::new(),
+        ));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry
+                .get(&SourceKey::Reference(1))
+                .and_then(|s| s.name.as_deref()),
+            Some("b2")
+        );
+
+        registry
+            .on_loaded_source_event(&LoadedSourceEvent::new(LoadedSourceEventReason::Removed, a));
+        assert_eq!(
+            registry.sources().cloned().collect::<Vec<_>>(),
+            vec![b.name("b2".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_loaded_sources_response_replaces_sources() {
+        let mut registry = LoadedSourceRegistry::new();
+        registry.on_loaded_source_event(&LoadedSourceEvent::new(
+            LoadedSourceEventReason::New,
+            Source::new().path("/old.rs".to_owned()),
+        ));
+        registry.on_loaded_sources_response(&LoadedSourcesResponse::new(vec![
+            Source::new().path("/b.rs".to_owned()),
+            Source::new().path("/a.rs".to_owned()),
+            Source::new(),
+        ]));
+        let paths = registry
+            .sources()
+            .filter_map(|s| s.path.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["/b.rs", "/a.rs"]);
+        assert!(registry
+            .get(&SourceKey::Path("/old.rs".to_owned()))
+            .is_none());
+    }
+}
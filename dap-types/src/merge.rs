@@ -0,0 +1,85 @@
+/// A type whose fields are all optional, which can be updated with another value that sets only
+/// some of them, like `Capabilities` with the body of a `capabilities` event.
+pub trait Merge {
+    /// Returns a copy of `self` with every field that is set in `other` replaced.
+    fn merge(&self, other: Self) -> Self;
+
+    /// Returns the protocol names of the fields that `other` sets to a value different from the
+    /// one in `self`, i.e. the fields that [`Merge::merge`] would change.
+    fn changed_fields(&self, other: &Self) -> Vec<&'static str>;
+}
+
+#[doc(hidden)]
+pub fn merge_field<T: Clone>(current: &Option<T>, update: Option<T>) -> Option<T> {
+    update.or_else(|| current.clone())
+}
+
+#[doc(hidden)]
+pub fn field_changed<T: PartialEq>(current: &Option<T>, update: &Option<T>) -> bool {
+    update.is_some() && update != current
+}
+
+/// Implements [`Merge`] for a struct, given its fields and their protocol names. Used by the
+/// generated types.
+macro_rules! impl_merge {
+    ($ty:ident { $($field:ident: $name:literal,)* }) => {
+        impl $crate::merge::Merge for $ty {
+            fn merge(&self, other: Self) -> Self {
+                Self {
+                    $($field: $crate::merge::merge_field(&self.$field, other.$field),)*
+                    #[cfg(feature = "extra-fields")]
+                    extra: {
+                        let mut extra = self.extra.clone();
+                        extra.extend(other.extra);
+                        extra
+                    },
+                }
+            }
+
+            fn changed_fields(&self, other: &Self) -> Vec<&'static str> {
+                let mut changed = Vec::new();
+                $(
+                    if $crate::merge::field_changed(&self.$field, &other.$field) {
+                        changed.push($name);
+                    }
+                )*
+                changed
+            }
+        }
+    };
+}
+
+pub(crate) use impl_merge;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Capabilities, ValueFormat};
+
+    #[test]
+    fn test_merge_capabilities() {
+        // `Capabilities` has inherent methods, so callers do not need to import `Merge`.
+        let initial = Capabilities {
+            supports_step_back: Some(true),
+            supports_restart_frame: Some(false),
+            ..Default::default()
+        };
+        let update = Capabilities {
+            supports_step_back: Some(true),
+            supports_restart_frame: Some(true),
+            supports_modules_request: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            initial.changed_fields(&update),
+            vec!["supportsRestartFrame", "supportsModulesRequest"]
+        );
+        let merged = initial.merge(update);
+        assert_eq!(merged.supports_step_back, Some(true));
+        assert_eq!(merged.supports_restart_frame, Some(true));
+        assert_eq!(merged.supports_modules_request, Some(true));
+        assert!(merged.changed_fields(&Capabilities::default()).is_empty());
+
+        let format = ValueFormat::new().hex(true);
+        assert_eq!(super::Merge::merge(&format, ValueFormat::new()), format);
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     BreakpointEvent, Capabilities, CapabilitiesEvent, ContinuedEvent, ExitedEvent,
     InvalidatedEvent, LoadedSourceEvent, MemoryEvent, ModuleEvent, OutputEvent, ProcessEvent,
     ProgressEndEvent, ProgressStartEvent, ProgressUpdateEvent, StoppedEvent, TerminatedEvent,
-    ThreadEvent,
+    ThreadEvent, UnsupportedRequestError,
 };
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -24,6 +24,24 @@ pub struct Request {
     pub arguments: Option<Value>,
 }
 
+impl Request {
+    /// Builds a request of type `R`, refusing it if the adapter's capabilities do not allow it.
+    pub fn checked<R: crate::requests::Request>(
+        seq: u64,
+        arguments: &R::Arguments,
+        capabilities: &Capabilities,
+    ) -> Result<Self, UnsupportedRequestError> {
+        capabilities.check_supported::<R>()?;
+        let arguments =
+            serde_json::to_value(arguments).expect("protocol arguments serialize to JSON");
+        Ok(Request {
+            seq,
+            command: R::COMMAND.to_owned(),
+            arguments: (!arguments.is_null()).then_some(arguments),
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Response {
     pub seq: u64,
@@ -145,4 +163,78 @@ mod tests {
             panic!("Expected Other variant for unknown event");
         }
     }
+
+    #[test]
+    fn test_checked_request() {
+        use crate::requests::{Continue, StepBack};
+
+        let capabilities = Capabilities::default();
+        let err = Request::checked::<StepBack>(1, &crate::StepBackArguments::new(1), &capabilities)
+            .unwrap_err();
+        assert_eq!(err.capability, Some("supportsStepBack"));
+
+        let request =
+            Request::checked::<Continue>(2, &crate::ContinueArguments::new(1), &capabilities)
+                .unwrap();
+        assert_eq!(request.command, "continue");
+        assert_eq!(request.arguments, Some(json!({ "threadId": 1 })));
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn test_extra_fields_round_trip() {
+        let json = json!({
+            "id": 1,
+            "verified": true,
+            "line": 3,
+            "vendorHint": { "kind": "hardware" }
+        });
+        let breakpoint: crate::Breakpoint = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(breakpoint.line, Some(3));
+        assert_eq!(
+            breakpoint.extra.get("vendorHint"),
+            Some(&json!({ "kind": "hardware" }))
+        );
+        assert_eq!(serde_json::to_value(&breakpoint).unwrap(), json);
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn test_nested_extra_fields_round_trip() {
+        let json = json!({
+            "stackFrames": [{
+                "id": 1,
+                "name": "main",
+                "line": 3,
+                "column": 1,
+                "source": { "path": "/src/main.go", "goPackage": "main" },
+                "goroutine": 7
+            }]
+        });
+        let response: crate::StackTraceResponse = serde_json::from_value(json.clone()).unwrap();
+        let frame = &response.stack_frames[0];
+        assert_eq!(frame.extra.get("goroutine"), Some(&json!(7)));
+        let source = frame.source.as_ref().unwrap();
+        assert_eq!(source.extra.get("goPackage"), Some(&json!("main")));
+        assert_eq!(serde_json::to_value(&response).unwrap(), json);
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn test_event_body_extra_fields_round_trip() {
+        let json = json!({
+            "event": "stopped",
+            "body": {
+                "reason": "breakpoint",
+                "threadId": 1,
+                "goroutineId": 7
+            }
+        });
+        let event: Events = serde_json::from_value(json.clone()).unwrap();
+        let Events::Stopped(stopped) = &event else {
+            panic!("expected a stopped event, got {event}");
+        };
+        assert_eq!(stopped.extra.get("goroutineId"), Some(&json!(7)));
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+    }
 }
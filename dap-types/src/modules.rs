@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    Capabilities, ColumnDescriptor, ColumnDescriptorType, Module, ModuleEvent, ModuleEventReason,
+    ModuleId, ModulesArguments, ModulesResponse,
+};
+
+/// The set of modules loaded by the debuggee, kept up to date with `module` events and paged
+/// `modules` requests.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleRegistry {
+    modules: HashMap<ModuleId, Module>,
+    /// Module ids in the order they were first seen.
+    order: Vec<ModuleId>,
+    paging: Option<Paging>,
+}
+
+#[derive(Debug, Clone)]
+struct Paging {
+    page_size: u64,
+    next_start: u64,
+    /// Module ids received during this fetch.
+    received: HashSet<ModuleId>,
+    done: bool,
+}
+
+impl ModuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: &ModuleId) -> Option<&Module> {
+        self.modules.get(id)
+    }
+
+    /// Modules in the order they were first reported.
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.order.iter().map(|id| &self.modules[id])
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn on_module_event(&mut self, event: &ModuleEvent) {
+        match event.reason {
+            ModuleEventReason::New | ModuleEventReason::Changed => {
+                self.upsert(event.module.clone())
+            }
+            ModuleEventReason::Removed => {
+                if self.modules.remove(&event.module.id).is_some() {
+                    self.order.retain(|id| id != &event.module.id);
+                }
+            }
+        }
+    }
+
+    /// Starts fetching all modules with `modules` requests of `page_size` modules each, or in a
+    /// single request if `page_size` is 0. Modules already known are kept and updated.
+    pub fn start_fetch(&mut self, page_size: u64) {
+        self.paging = Some(Paging {
+            page_size,
+            next_start: 0,
+            received: HashSet::new(),
+            done: false,
+        });
+    }
+
+    /// Arguments for the next `modules` request of a fetch started with
+    /// [`ModuleRegistry::start_fetch`], or `None` if there is nothing left to fetch.
+    pub fn next_page_request(&self) -> Option<ModulesArguments> {
+        let paging = self.paging.as_ref().filter(|p| !p.done)?;
+        let mut args = ModulesArguments::new().start_module(paging.next_start);
+        args.module_count = (paging.page_size > 0).then_some(paging.page_size);
+        Some(args)
+    }
+
+    /// Applies the response to the request returned by [`ModuleRegistry::next_page_request`].
+    ///
+    /// The fetch also ends when the adapter evidently ignores the paging arguments, by returning
+    /// more modules than requested or only modules already received during the fetch, so that
+    /// it does not go on requesting the same modules.
+    pub fn on_modules_response(&mut self, response: &ModulesResponse) {
+        let received = response.modules.len() as u64;
+        for module in &response.modules {
+            self.upsert(module.clone());
+        }
+        if let Some(paging) = &mut self.paging {
+            let mut added = 0;
+            for module in &response.modules {
+                if paging.received.insert(module.id.clone()) {
+                    added += 1;
+                }
+            }
+            paging.next_start += received;
+            paging.done = paging.page_size == 0
+                || received < paging.page_size
+                || received > paging.page_size
+                || added == 0
+                || response
+                    .total_modules
+                    .is_some_and(|total| paging.next_start >= total);
+        }
+    }
+
+    /// Returns whether a fetch started with [`ModuleRegistry::start_fetch`] has completed.
+    pub fn is_fetch_complete(&self) -> bool {
+        self.paging.as_ref().is_none_or(|p| p.done)
+    }
+
+    pub fn clear(&mut self) {
+        self.modules.clear();
+        self.order.clear();
+        self.paging = None;
+    }
+
+    fn upsert(&mut self, module: Module) {
+        if !self.modules.contains_key(&module.id) {
+            self.order.push(module.id.clone());
+        }
+        self.modules.insert(module.id.clone(), module);
+    }
+}
+
+fn column(attribute_name: &str, label: &str, type_: ColumnDescriptorType) -> ColumnDescriptor {
+    ColumnDescriptor::new(attribute_name.to_owned(), label.to_owned()).type_(type_)
+}
+
+/// The columns of a modules view: the standard `Module` attributes followed by the
+/// `additionalModuleColumns` advertised by the adapter.
+pub fn module_columns(capabilities: &Capabilities) -> Vec<ColumnDescriptor> {
+    let mut columns = vec![
+        column("name", "Name", ColumnDescriptorType::String),
+        column("path", "Path", ColumnDescriptorType::String),
+        column("isOptimized", "Optimized", ColumnDescriptorType::Boolean),
+        column("isUserCode", "User Code", ColumnDescriptorType::Boolean),
+        column("version", "Version", ColumnDescriptorType::String),
+        column(
+            "symbolStatus",
+            "Symbol Status",
+            ColumnDescriptorType::String,
+        ),
+        column(
+            "symbolFilePath",
+            "Symbol File",
+            ColumnDescriptorType::String,
+        ),
+        column("dateTimeStamp", "Timestamp", ColumnDescriptorType::String),
+        column(
+            "addressRange",
+            "Address Range",
+            ColumnDescriptorType::String,
+        ),
+    ];
+    for additional in capabilities.additional_module_columns.iter().flatten() {
+        columns.retain(|c| c.attribute_name != additional.attribute_name);
+        columns.push(additional.clone());
+    }
+    columns
+}
+
+/// The value of a module attribute, looked up by its protocol name.
+enum Attribute<'a> {
+    Text(&'a str),
+    Number(i64),
+    Bool(bool),
+    #[cfg(feature = "extra-fields")]
+    Json(&'a serde_json::Value),
+}
+
+fn attribute<'a>(module: &'a Module, name: &str) -> Option<Attribute<'a>> {
+    let text = |value: &'a Option<String>| value.as_deref().map(Attribute::Text);
+    match name {
+        "id" => Some(match &module.id {
+            ModuleId::Number(id) => Attribute::Number(i64::from(*id)),
+            ModuleId::String(id) => Attribute::Text(id),
+        }),
+        "name" => Some(Attribute::Text(&module.name)),
+        "path" => text(&module.path),
+        "isOptimized" => module.is_optimized.map(Attribute::Bool),
+        "isUserCode" => module.is_user_code.map(Attribute::Bool),
+        "version" => text(&module.version),
+        "symbolStatus" => text(&module.symbol_status),
+        "symbolFilePath" => text(&module.symbol_file_path),
+        "dateTimeStamp" => text(&module.date_time_stamp),
+        "addressRange" => text(&module.address_range),
+        #[cfg(feature = "extra-fields")]
+        _ => match module.extra.get(name)? {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some(Attribute::Text(s)),
+            serde_json::Value::Bool(b) => Some(Attribute::Bool(*b)),
+            value => Some(
+                value
+                    .as_i64()
+                    .map_or(Attribute::Json(value), Attribute::Number),
+            ),
+        },
+        #[cfg(not(feature = "extra-fields"))]
+        _ => None,
+    }
+}
+
+/// Renders the value of a module attribute for display in a column.
+///
+/// The attribute is looked up by its protocol name (`attributeName`). Attributes that are not
+/// defined by the protocol can only be rendered with the `extra-fields` feature, which keeps
+/// them in [`Module::extra`](crate::Module). The value is formatted according to the column's
+/// `type` (defaulting to `string`), and then placed into the column's `format`, if any.
+///
+/// The protocol does not define the syntax of `format` yet. It is used as a display template in
+/// which every `{}` is replaced with the value, e.g. `v{}` for a version column. A `format`
+/// without `{}` is not a template and is not applied. Missing attributes render as an empty
+/// cell, without the template.
+pub fn render_cell(module: &Module, column: &ColumnDescriptor) -> String {
+    let Some(value) = attribute(module, &column.attribute_name) else {
+        return String::new();
+    };
+    let text = render_value(column.type_.unwrap_or(ColumnDescriptorType::String), value);
+    match &column.format {
+        Some(format) if format.contains("{}") => format.replace("{}", &text),
+        _ => text,
+    }
+}
+
+fn render_value(type_: ColumnDescriptorType, value: Attribute<'_>) -> String {
+    match (type_, value) {
+        (ColumnDescriptorType::UnixTimestampUtc, Attribute::Number(n)) => format_unix_timestamp(n),
+        (ColumnDescriptorType::Boolean, Attribute::Bool(b)) => {
+            if b { "Yes" } else { "No" }.to_owned()
+        }
+        (_, Attribute::Text(s)) => s.to_owned(),
+        (_, Attribute::Number(n)) => n.to_string(),
+        (_, Attribute::Bool(b)) => b.to_string(),
+        #[cfg(feature = "extra-fields")]
+        (_, Attribute::Json(value)) => value.to_string(),
+    }
+}
+
+/// Renders a row of cells, fitting each one to its column's `width` hint (if any) by padding or
+/// truncating it.
+pub fn render_row(module: &Module, columns: &[ColumnDescriptor]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|column| {
+            let text = render_cell(module, column);
+            match column.width {
+                Some(width) => fit_width(&text, width as usize),
+                None => text,
+            }
+        })
+        .collect()
+}
+
+fn fit_width(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    if len <= width {
+        format!("{text:width$}")
+    } else if width == 0 {
+        String::new()
+    } else {
+        let mut truncated = text.chars().take(width - 1).collect::<String>();
+        truncated.push('…');
+        truncated
+    }
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+fn format_unix_timestamp(seconds: i64) -> String {
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_paging_and_events() {
+        let mut registry = ModuleRegistry::new();
+        registry.start_fetch(2);
+        let args = registry.next_page_request().unwrap();
+        assert_eq!(args.start_module, Some(0));
+        assert_eq!(args.module_count, Some(2));
+        registry.on_modules_response(
+            &ModulesResponse::new(vec![
+                Module::new(ModuleId::Number(1), "a".to_owned()),
+                Module::new(ModuleId::Number(2), "b".to_owned()),
+            ])
+            .total_modules(3),
+        );
+        assert_eq!(registry.next_page_request().unwrap().start_module, Some(2));
+        registry.on_modules_response(
+            &ModulesResponse::new(vec![Module::new(ModuleId::Number(3), "c".to_owned())])
+                .total_modules(3),
+        );
+        assert!(registry.is_fetch_complete());
+        assert_eq!(registry.next_page_request(), None);
+
+        registry.on_module_event(&ModuleEvent::new(
+            ModuleEventReason::Removed,
+            Module::new(ModuleId::Number(2), String::new()),
+        ));
+        registry.on_module_event(&ModuleEvent::new(
+            ModuleEventReason::Changed,
+            Module::new(ModuleId::Number(1), "a2".to_owned()),
+        ));
+        let names = registry
+            .modules()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a2", "c"]);
+    }
+
+    #[test]
+    fn test_paging_ignored_by_adapter() {
+        let page = || {
+            ModulesResponse::new(vec![
+                Module::new(ModuleId::Number(1), "a".to_owned()),
+                Module::new(ModuleId::Number(2), "b".to_owned()),
+            ])
+        };
+        let mut registry = ModuleRegistry::new();
+        registry.start_fetch(2);
+        registry.on_modules_response(&page());
+        assert!(!registry.is_fetch_complete());
+        // The adapter returned the first page again for `startModule: 2`.
+        registry.on_modules_response(&page());
+        assert!(registry.is_fetch_complete());
+        assert_eq!(registry.len(), 2);
+
+        // Modules known from an earlier fetch do not end a new one.
+        registry.start_fetch(2);
+        registry.on_modules_response(&page());
+        assert!(!registry.is_fetch_complete());
+
+        registry.start_fetch(1);
+        registry.on_modules_response(&page());
+        assert!(registry.is_fetch_complete());
+    }
+
+    #[test]
+    fn test_render_cells() {
+        let module = Module::new(ModuleId::Number(1), "libfoo.so".to_owned()).is_optimized(true);
+        let mut name = column("name", "Name", ColumnDescriptorType::String);
+        name.width = Some(5);
+        let optimized = column("isOptimized", "Optimized", ColumnDescriptorType::Boolean);
+        assert_eq!(
+            render_row(&module, &[name, optimized]),
+            vec!["libf…".to_owned(), "Yes".to_owned()]
+        );
+        let mut loaded = column("dateTimeStamp", "Loaded", ColumnDescriptorType::String);
+        loaded.format = Some("loaded {}".to_owned());
+        let stamped = module.clone().date_time_stamp("2024-05-01".to_owned());
+        assert_eq!(render_cell(&stamped, &loaded), "loaded 2024-05-01");
+        assert_eq!(render_cell(&module, &loaded), "");
+        loaded.format = Some("%Y-%m-%d".to_owned());
+        assert_eq!(render_cell(&stamped, &loaded), "2024-05-01");
+        let id = column("id", "Id", ColumnDescriptorType::Number);
+        assert_eq!(render_cell(&module, &id), "1");
+        assert_eq!(format_unix_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_unix_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+
+use crate::{OutputEvent, OutputEventCategory, OutputEventGroup, Source};
+
+/// Identifier of an [`OutputGroup`] within an [`OutputLog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OutputGroupId(pub u64);
+
+/// A single line (or structured value) of output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputEntry {
+    /// The category of the output. A missing or unknown category is stored as `Console`, as the
+    /// protocol requires.
+    pub category: OutputEventCategory,
+    /// The text of the entry, without the trailing line break.
+    pub text: String,
+    /// Whether the line has been terminated by a line break. Output that is not terminated is
+    /// merged with subsequent output of the same category.
+    pub complete: bool,
+    /// Reference to pass to a `variables` request to expand structured output, if any.
+    pub variables_reference: Option<i64>,
+    /// Reference to pass to a `locations` request, if any.
+    pub location_reference: Option<i64>,
+    pub source: Option<Box<Source>>,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+    pub data: Option<serde_json::Value>,
+}
+
+impl OutputEntry {
+    /// Returns whether the entry can be expanded with a `variables` request.
+    pub fn is_structured(&self) -> bool {
+        self.variables_reference.is_some_and(|r| r > 0)
+    }
+
+    fn from_event(event: &OutputEvent, text: &str, complete: bool) -> Self {
+        OutputEntry {
+            category: normalize_category(event.category.as_ref()),
+            text: text.to_owned(),
+            complete,
+            variables_reference: event.variables_reference.filter(|r| *r > 0),
+            location_reference: event.location_reference.filter(|r| *r > 0),
+            source: event.source.clone().map(Box::new),
+            line: event.line,
+            column: event.column,
+            data: event.data.clone(),
+        }
+    }
+
+    fn has_references(&self) -> bool {
+        self.variables_reference.is_some() || self.location_reference.is_some()
+    }
+}
+
+/// A group of output started by an event with `group` set to `start` or `startCollapsed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputGroup {
+    pub id: OutputGroupId,
+    /// The event that started the group; its text is the name of the group.
+    pub header: OutputEntry,
+    pub collapsed: bool,
+    pub children: Vec<OutputNode>,
+    /// The non-empty output of the event that ended the group, shown unindented after it.
+    pub footer: Option<OutputEntry>,
+    /// Whether an `end` event has been received for the group.
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputNode {
+    Entry(OutputEntry),
+    Group(Box<OutputGroup>),
+}
+
+/// Output log model that organizes a stream of `output` events into a tree of groups.
+///
+/// - Output is split into lines, and unterminated output is merged with the next output of the
+///   same category, even if groups have started or ended in between.
+/// - Structured output (with a `variablesReference` or `locationReference`) is never merged, so
+///   its references stay attached to the entry that carried them.
+/// - Output with category `telemetry` is not part of the log; it is queued separately and can be
+///   retrieved with [`OutputLog::take_telemetry`].
+#[derive(Debug, Clone, Default)]
+pub struct OutputLog {
+    nodes: Vec<OutputNode>,
+    open_groups: Vec<usize>,
+    next_group_id: u64,
+    telemetry: Vec<OutputEvent>,
+    /// Paths to the unterminated entry of each category, which the next output of that
+    /// category is merged into, even when groups have started or ended in between.
+    partial: HashMap<OutputEventCategory, Vec<usize>>,
+}
+
+impl OutputLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Top level nodes of the log.
+    pub fn nodes(&self) -> &[OutputNode] {
+        &self.nodes
+    }
+
+    /// Returns the group that subsequent output will be added to, if any.
+    pub fn current_group_id(&self) -> Option<OutputGroupId> {
+        (!self.open_groups.is_empty()).then(|| self.current_group().id)
+    }
+
+    pub fn push(&mut self, event: OutputEvent) {
+        if event.category == Some(OutputEventCategory::Telemetry) {
+            self.telemetry.push(event);
+            return;
+        }
+        match event.group {
+            Some(OutputEventGroup::Start) | Some(OutputEventGroup::StartCollapsed) => {
+                let id = OutputGroupId(self.next_group_id);
+                self.next_group_id += 1;
+                let group = OutputGroup {
+                    id,
+                    header: OutputEntry::from_event(&event, trim_line_break(&event.output), true),
+                    collapsed: event.group == Some(OutputEventGroup::StartCollapsed),
+                    children: Vec::new(),
+                    footer: None,
+                    closed: false,
+                };
+                let container = self.container_mut();
+                container.push(OutputNode::Group(Box::new(group)));
+                let index = container.len() - 1;
+                self.open_groups.push(index);
+            }
+            Some(OutputEventGroup::End) => {
+                // An `end` without a matching `start` is ignored, except for its output.
+                if self.open_groups.is_empty() {
+                    self.push_text(&event);
+                    return;
+                }
+                let text = trim_line_break(&event.output);
+                let footer =
+                    (!text.is_empty()).then(|| OutputEntry::from_event(&event, text, true));
+                let group = self.current_group_mut();
+                group.footer = footer;
+                group.closed = true;
+                self.open_groups.pop();
+            }
+            None => self.push_text(&event),
+        }
+    }
+
+    /// Removes and returns output events with category `telemetry` received so far.
+    pub fn take_telemetry(&mut self) -> Vec<OutputEvent> {
+        std::mem::take(&mut self.telemetry)
+    }
+
+    pub fn group_mut(&mut self, id: OutputGroupId) -> Option<&mut OutputGroup> {
+        fn find(nodes: &mut [OutputNode], id: OutputGroupId) -> Option<&mut OutputGroup> {
+            for node in nodes {
+                let OutputNode::Group(group) = node else {
+                    continue;
+                };
+                if group.id == id {
+                    return Some(group);
+                }
+                if let Some(found) = find(&mut group.children, id) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find(&mut self.nodes, id)
+    }
+
+    pub fn set_collapsed(&mut self, id: OutputGroupId, collapsed: bool) {
+        if let Some(group) = self.group_mut(id) {
+            group.collapsed = collapsed;
+        }
+    }
+
+    /// Flattens the log into rows for display, skipping the contents of collapsed groups. Each
+    /// row is paired with its indentation depth.
+    pub fn visible_entries(&self) -> Vec<(usize, &OutputEntry)> {
+        fn walk<'a>(
+            nodes: &'a [OutputNode],
+            depth: usize,
+            out: &mut Vec<(usize, &'a OutputEntry)>,
+        ) {
+            for node in nodes {
+                match node {
+                    OutputNode::Entry(entry) => out.push((depth, entry)),
+                    OutputNode::Group(group) => {
+                        out.push((depth, &group.header));
+                        if !group.collapsed {
+                            walk(&group.children, depth + 1, out);
+                        }
+                        if let Some(footer) = &group.footer {
+                            out.push((depth, footer));
+                        }
+                    }
+                }
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.nodes, 0, &mut out);
+        out
+    }
+
+    /// Removes all output, including queued telemetry, closing any open groups.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.open_groups.clear();
+        self.telemetry.clear();
+        self.partial.clear();
+    }
+
+    fn push_text(&mut self, event: &OutputEvent) {
+        let mut incoming = OutputEntry::from_event(event, "", false);
+        let mut rest = event.output.as_str();
+        // Structured output keeps its text in a single entry, so the reference stays with it.
+        if incoming.has_references() {
+            incoming.complete = true;
+            incoming.text = trim_line_break(rest).to_owned();
+            self.container_mut().push(OutputNode::Entry(incoming));
+            return;
+        }
+        if let Some(path) = self.partial.remove(&incoming.category) {
+            if let OutputNode::Entry(partial) = node_mut(&mut self.nodes, &path) {
+                let (head, tail) = split_line(rest);
+                partial.text.push_str(head);
+                partial.complete = tail.is_some();
+                match tail {
+                    Some(tail) if !tail.is_empty() => rest = tail,
+                    Some(_) => return,
+                    None => {
+                        self.partial.insert(incoming.category, path);
+                        return;
+                    }
+                }
+            }
+        }
+        while !rest.is_empty() {
+            let (head, tail) = split_line(rest);
+            let mut entry = incoming.clone();
+            entry.text = head.to_owned();
+            entry.complete = tail.is_some();
+            let container = self.container_mut();
+            container.push(OutputNode::Entry(entry));
+            if tail.is_none() {
+                let index = container.len() - 1;
+                let mut path = self.open_groups.clone();
+                path.push(index);
+                self.partial.insert(incoming.category.clone(), path);
+            }
+            rest = tail.unwrap_or_default();
+        }
+    }
+
+    fn container_mut(&mut self) -> &mut Vec<OutputNode> {
+        if self.open_groups.is_empty() {
+            &mut self.nodes
+        } else {
+            &mut self.current_group_mut().children
+        }
+    }
+
+    fn current_group_mut(&mut self) -> &mut OutputGroup {
+        assert!(!self.open_groups.is_empty(), "no open group");
+        match node_mut(&mut self.nodes, &self.open_groups) {
+            OutputNode::Group(group) => group,
+            OutputNode::Entry(_) => unreachable!("open group index points at an entry"),
+        }
+    }
+
+    fn current_group(&self) -> &OutputGroup {
+        let mut nodes = &self.nodes;
+        let mut group = None;
+        for index in &self.open_groups {
+            match &nodes[*index] {
+                OutputNode::Group(g) => {
+                    nodes = &g.children;
+                    group = Some(g);
+                }
+                OutputNode::Entry(_) => unreachable!("open group index points at an entry"),
+            }
+        }
+        group.expect("no open group")
+    }
+}
+
+/// Returns the node at `path`, a list of indices into `nodes` and the children of groups.
+fn node_mut<'a>(mut nodes: &'a mut Vec<OutputNode>, path: &[usize]) -> &'a mut OutputNode {
+    let (last, parents) = path.split_last().expect("empty node path");
+    for index in parents {
+        match &mut nodes[*index] {
+            OutputNode::Group(group) => nodes = &mut group.children,
+            OutputNode::Entry(_) => unreachable!("node path points into an entry"),
+        }
+    }
+    &mut nodes[*last]
+}
+
+fn normalize_category(category: Option<&OutputEventCategory>) -> OutputEventCategory {
+    match category {
+        None | Some(OutputEventCategory::Other(_)) => OutputEventCategory::Console,
+        Some(category) => category.clone(),
+    }
+}
+
+/// Splits off the first line, returning the rest after the line break if there was one.
+fn split_line(text: &str) -> (&str, Option<&str>) {
+    match text.find('\n') {
+        Some(index) => (
+            text[..index].strip_suffix('\r').unwrap_or(&text[..index]),
+            Some(&text[index + 1..]),
+        ),
+        None => (text, None),
+    }
+}
+
+fn trim_line_break(text: &str) -> &str {
+    text.strip_suffix('\n')
+        .map(|t| t.strip_suffix('\r').unwrap_or(t))
+        .unwrap_or(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_log_groups_and_partial_lines() {
+        let mut log = OutputLog::new();
+        log.push(OutputEvent::new("hel".to_owned()).category(OutputEventCategory::Stdout));
+        log.push(OutputEvent::new("lo\nwor".to_owned()).category(OutputEventCategory::Stdout));
+        log.push(OutputEvent::new("{}".to_owned()).category(OutputEventCategory::Telemetry));
+        log.push(
+            OutputEvent::new("tests\n".to_owned())
+                .category(OutputEventCategory::Console)
+                .group(OutputEventGroup::StartCollapsed),
+        );
+        log.push(OutputEvent::new("passed\n".to_owned()).category(OutputEventCategory::Console));
+        log.push(
+            OutputEvent::new("obj\n".to_owned())
+                .category(OutputEventCategory::Console)
+                .variables_reference(7),
+        );
+        log.push(
+            OutputEvent::new(String::new())
+                .category(OutputEventCategory::Console)
+                .group(OutputEventGroup::End),
+        );
+        log.push(OutputEvent::new("ld\n".to_owned()).category(OutputEventCategory::Stdout));
+
+        assert_eq!(log.take_telemetry().len(), 1);
+        assert_eq!(log.current_group_id(), None);
+
+        let rows = |log: &OutputLog| {
+            log.visible_entries()
+                .into_iter()
+                .map(|(depth, entry)| (depth, entry.text.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rows(&log),
+            vec![
+                (0, "hello".to_owned()),
+                (0, "world".to_owned()),
+                (0, "tests".to_owned()),
+            ]
+        );
+
+        let OutputNode::Group(group) = &log.nodes()[2] else {
+            panic!("expected a group");
+        };
+        let id = group.id;
+        log.set_collapsed(id, false);
+        let visible = log.visible_entries();
+        assert_eq!(visible[3].0, 1);
+        assert_eq!(visible[4].1.variables_reference, Some(7));
+
+        log.push(OutputEvent::new("err".to_owned()).category(OutputEventCategory::Stderr));
+        log.push(OutputEvent::new("{}".to_owned()).category(OutputEventCategory::Telemetry));
+        log.clear();
+        assert!(log.take_telemetry().is_empty());
+        log.push(OutputEvent::new("or\n".to_owned()).category(OutputEventCategory::Stderr));
+        assert_eq!(rows(&log), vec![(0, "or".to_owned())]);
+    }
+}
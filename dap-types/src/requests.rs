@@ -9,24 +9,36 @@ pub trait Request {
     const COMMAND: &'static str;
     type Arguments: Debug + Clone + Serialize + DeserializeOwned + Send + Sync;
     type Response: Debug + Clone + Serialize + DeserializeOwned + Send + Sync;
+    /// The adapter capability that must be true for a client to send this request, if any.
+    const CAPABILITY: Option<&'static str> = None;
+
+    /// Returns whether the adapter supports this request, according to its capabilities.
+    fn is_supported(_capabilities: &crate::Capabilities) -> bool {
+        true
+    }
 }
 
 /// The `cancel` request is used by the client in two situations:
 /// - to indicate that it is no longer interested in the result produced by a specific request issued earlier
 /// - to cancel a progress sequence.
+///
 /// Clients should only call this request if the corresponding capability `supportsCancelRequest` is true.
 /// This request has a hint characteristic: a debug adapter can only be expected to make a 'best effort' in honoring this request but there are no guarantees.
 /// The `cancel` request may return an error if it could not cancel an operation but a client should refrain from presenting this error to end users.
 /// The request that got cancelled still needs to send a response back. This can either be a normal result (`success` attribute true) or an error response (`success` attribute false and the `message` set to `cancelled`).
 /// Returning partial results from a cancelled request is possible but please note that a client has no generic way for detecting that a response is partial or not.
 /// The progress that got cancelled still needs to send a `progressEnd` event back.
-///  A client should not assume that progress just got cancelled after sending the `cancel` request.
+/// A client should not assume that progress just got cancelled after sending the `cancel` request.
 pub enum Cancel {}
 
 impl Request for Cancel {
     const COMMAND: &'static str = "cancel";
     type Arguments = crate::CancelArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsCancelRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_cancel_request == Some(true)
+    }
 }
 
 /// This request is sent from the debug adapter to the client to run a command in a terminal.
@@ -74,6 +86,10 @@ impl Request for ConfigurationDone {
     const COMMAND: &'static str = "configurationDone";
     type Arguments = crate::ConfigurationDoneArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsConfigurationDoneRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_configuration_done_request == Some(true)
+    }
 }
 
 /// This launch request is sent from the client to the debug adapter to start the debuggee with or without debugging (if `noDebug` is true).
@@ -104,6 +120,10 @@ impl Request for Restart {
     const COMMAND: &'static str = "restart";
     type Arguments = crate::RestartArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsRestartRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_restart_request == Some(true)
+    }
 }
 
 /// The `disconnect` request asks the debug adapter to disconnect from the debuggee (thus ending the debug session) and then to shut down itself (the debug adapter).
@@ -127,6 +147,10 @@ impl Request for Terminate {
     const COMMAND: &'static str = "terminate";
     type Arguments = crate::TerminateArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsTerminateRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_terminate_request == Some(true)
+    }
 }
 
 /// The `breakpointLocations` request returns all possible locations for source breakpoints in a given range.
@@ -137,6 +161,10 @@ impl Request for BreakpointLocations {
     const COMMAND: &'static str = "breakpointLocations";
     type Arguments = crate::BreakpointLocationsArguments;
     type Response = crate::BreakpointLocationsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsBreakpointLocationsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_breakpoint_locations_request == Some(true)
+    }
 }
 
 /// Sets multiple breakpoints for a single source and clears all previous breakpoints in that source.
@@ -160,6 +188,10 @@ impl Request for SetFunctionBreakpoints {
     const COMMAND: &'static str = "setFunctionBreakpoints";
     type Arguments = crate::SetFunctionBreakpointsArguments;
     type Response = crate::SetFunctionBreakpointsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsFunctionBreakpoints");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_function_breakpoints == Some(true)
+    }
 }
 
 /// The request configures the debugger's response to thrown exceptions. Each of the `filters`, `filterOptions`, and `exceptionOptions` in the request are independent configurations to a debug adapter indicating a kind of exception to catch. An exception thrown in a program should result in a `stopped` event from the debug adapter (with reason `exception`) if any of the configured filters match.
@@ -180,6 +212,10 @@ impl Request for DataBreakpointInfo {
     const COMMAND: &'static str = "dataBreakpointInfo";
     type Arguments = crate::DataBreakpointInfoArguments;
     type Response = crate::DataBreakpointInfoResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsDataBreakpoints");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_data_breakpoints == Some(true)
+    }
 }
 
 /// Replaces all existing data breakpoints with new data breakpoints.
@@ -192,6 +228,10 @@ impl Request for SetDataBreakpoints {
     const COMMAND: &'static str = "setDataBreakpoints";
     type Arguments = crate::SetDataBreakpointsArguments;
     type Response = crate::SetDataBreakpointsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsDataBreakpoints");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_data_breakpoints == Some(true)
+    }
 }
 
 /// Replaces all existing instruction breakpoints. Typically, instruction breakpoints would be set from a disassembly window.
@@ -204,6 +244,10 @@ impl Request for SetInstructionBreakpoints {
     const COMMAND: &'static str = "setInstructionBreakpoints";
     type Arguments = crate::SetInstructionBreakpointsArguments;
     type Response = crate::SetInstructionBreakpointsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsInstructionBreakpoints");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_instruction_breakpoints == Some(true)
+    }
 }
 
 /// The request resumes execution of all threads. If the debug adapter supports single thread execution (see capability `supportsSingleThreadExecutionRequests`), setting the `singleThread` argument to true resumes only the specified thread. If not all threads were resumed, the `allThreadsContinued` attribute of the response should be set to false.
@@ -262,6 +306,10 @@ impl Request for StepBack {
     const COMMAND: &'static str = "stepBack";
     type Arguments = crate::StepBackArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsStepBack");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_step_back == Some(true)
+    }
 }
 
 /// The request resumes backward execution of all threads. If the debug adapter supports single thread execution (see capability `supportsSingleThreadExecutionRequests`), setting the `singleThread` argument to true resumes only the specified thread. If not all threads were resumed, the `allThreadsContinued` attribute of the response should be set to false.
@@ -272,6 +320,10 @@ impl Request for ReverseContinue {
     const COMMAND: &'static str = "reverseContinue";
     type Arguments = crate::ReverseContinueArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsStepBack");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_step_back == Some(true)
+    }
 }
 
 /// The request restarts execution of the specified stack frame.
@@ -283,6 +335,10 @@ impl Request for RestartFrame {
     const COMMAND: &'static str = "restartFrame";
     type Arguments = crate::RestartFrameArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsRestartFrame");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_restart_frame == Some(true)
+    }
 }
 
 /// The request sets the location where the debuggee will continue to run.
@@ -296,6 +352,10 @@ impl Request for Goto {
     const COMMAND: &'static str = "goto";
     type Arguments = crate::GotoArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsGotoTargetsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_goto_targets_request == Some(true)
+    }
 }
 
 /// The request suspends the debuggee.
@@ -345,6 +405,10 @@ impl Request for SetVariable {
     const COMMAND: &'static str = "setVariable";
     type Arguments = crate::SetVariableArguments;
     type Response = crate::SetVariableResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsSetVariable");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_set_variable == Some(true)
+    }
 }
 
 /// The request retrieves the source code for a given source reference.
@@ -373,6 +437,10 @@ impl Request for TerminateThreads {
     const COMMAND: &'static str = "terminateThreads";
     type Arguments = crate::TerminateThreadsArguments;
     type Response = ();
+    const CAPABILITY: Option<&'static str> = Some("supportsTerminateThreadsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_terminate_threads_request == Some(true)
+    }
 }
 
 /// Modules can be retrieved from the debug adapter with this request which can either return all modules or a range of modules to support paging.
@@ -383,6 +451,10 @@ impl Request for Modules {
     const COMMAND: &'static str = "modules";
     type Arguments = crate::ModulesArguments;
     type Response = crate::ModulesResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsModulesRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_modules_request == Some(true)
+    }
 }
 
 /// Retrieves the set of all sources currently loaded by the debugged process.
@@ -393,6 +465,10 @@ impl Request for LoadedSources {
     const COMMAND: &'static str = "loadedSources";
     type Arguments = crate::LoadedSourcesArguments;
     type Response = crate::LoadedSourcesResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsLoadedSourcesRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_loaded_sources_request == Some(true)
+    }
 }
 
 /// Evaluates the given expression in the context of a stack frame.
@@ -415,6 +491,10 @@ impl Request for SetExpression {
     const COMMAND: &'static str = "setExpression";
     type Arguments = crate::SetExpressionArguments;
     type Response = crate::SetExpressionResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsSetExpression");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_set_expression == Some(true)
+    }
 }
 
 /// This request retrieves the possible step-in targets for the specified stack frame.
@@ -426,6 +506,10 @@ impl Request for StepInTargets {
     const COMMAND: &'static str = "stepInTargets";
     type Arguments = crate::StepInTargetsArguments;
     type Response = crate::StepInTargetsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsStepInTargetsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_step_in_targets_request == Some(true)
+    }
 }
 
 /// This request retrieves the possible goto targets for the specified source location.
@@ -437,6 +521,10 @@ impl Request for GotoTargets {
     const COMMAND: &'static str = "gotoTargets";
     type Arguments = crate::GotoTargetsArguments;
     type Response = crate::GotoTargetsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsGotoTargetsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_goto_targets_request == Some(true)
+    }
 }
 
 /// Returns a list of possible completions for a given caret position and text.
@@ -447,6 +535,10 @@ impl Request for Completions {
     const COMMAND: &'static str = "completions";
     type Arguments = crate::CompletionsArguments;
     type Response = crate::CompletionsResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsCompletionsRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_completions_request == Some(true)
+    }
 }
 
 /// Retrieves the details of the exception that caused this event to be raised.
@@ -457,6 +549,10 @@ impl Request for ExceptionInfo {
     const COMMAND: &'static str = "exceptionInfo";
     type Arguments = crate::ExceptionInfoArguments;
     type Response = crate::ExceptionInfoResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsExceptionInfoRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_exception_info_request == Some(true)
+    }
 }
 
 /// Reads bytes from memory at the provided location.
@@ -467,6 +563,10 @@ impl Request for ReadMemory {
     const COMMAND: &'static str = "readMemory";
     type Arguments = crate::ReadMemoryArguments;
     type Response = crate::ReadMemoryResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsReadMemoryRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_read_memory_request == Some(true)
+    }
 }
 
 /// Writes bytes to memory at the provided location.
@@ -477,6 +577,10 @@ impl Request for WriteMemory {
     const COMMAND: &'static str = "writeMemory";
     type Arguments = crate::WriteMemoryArguments;
     type Response = crate::WriteMemoryResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsWriteMemoryRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_write_memory_request == Some(true)
+    }
 }
 
 /// Disassembles code stored at the provided location.
@@ -487,6 +591,10 @@ impl Request for Disassemble {
     const COMMAND: &'static str = "disassemble";
     type Arguments = crate::DisassembleArguments;
     type Response = crate::DisassembleResponse;
+    const CAPABILITY: Option<&'static str> = Some("supportsDisassembleRequest");
+    fn is_supported(capabilities: &crate::Capabilities) -> bool {
+        capabilities.supports_disassemble_request == Some(true)
+    }
 }
 
 /// Looks up information about a location reference previously returned by the debug adapter.
@@ -497,3 +605,159 @@ impl Request for Locations {
     type Arguments = crate::LocationsArguments;
     type Response = crate::LocationsResponse;
 }
+
+/// Receives the types of a request whose command is only known at runtime, to check or coerce
+/// its arguments and response body.
+pub(crate) trait CommandVisitor {
+    type Output;
+
+    /// Called with the types the request arguments and the success response body are checked
+    /// as, and whether the protocol requires them.
+    fn visit<A, B>(self, arguments_required: bool, body_required: bool) -> Self::Output
+    where
+        A: crate::strict::Strict + crate::lenient::Lenient,
+        B: crate::strict::Strict + crate::lenient::Lenient;
+}
+
+/// Calls `visitor` with the types of the request `command`, or returns `None` if the protocol
+/// does not define it.
+pub(crate) fn visit_command<V: CommandVisitor>(command: &str, visitor: V) -> Option<V::Output> {
+    Some(match command {
+        "cancel" => visitor.visit::<crate::CancelArguments, serde_json::Value>(false, false),
+        "runInTerminal" => visitor.visit::<
+            crate::RunInTerminalRequestArguments,
+            crate::RunInTerminalResponse,
+        >(true, true),
+        "startDebugging" => visitor.visit::<
+            crate::StartDebuggingRequestArguments,
+            serde_json::Value,
+        >(true, false),
+        "initialize" => visitor.visit::<
+            crate::InitializeRequestArguments,
+            crate::Capabilities,
+        >(true, false),
+        "configurationDone" => visitor.visit::<
+            crate::ConfigurationDoneArguments,
+            serde_json::Value,
+        >(false, false),
+        "launch" => visitor.visit::<crate::LaunchRequestArguments, serde_json::Value>(true, false),
+        "attach" => visitor.visit::<crate::AttachRequestArguments, serde_json::Value>(true, false),
+        "restart" => visitor.visit::<crate::RestartArguments, serde_json::Value>(false, false),
+        "disconnect" => visitor.visit::<
+            crate::DisconnectArguments,
+            serde_json::Value,
+        >(false, false),
+        "terminate" => visitor.visit::<crate::TerminateArguments, serde_json::Value>(false, false),
+        "breakpointLocations" => visitor.visit::<
+            crate::BreakpointLocationsArguments,
+            crate::BreakpointLocationsResponse,
+        >(false, true),
+        "setBreakpoints" => visitor.visit::<
+            crate::SetBreakpointsArguments,
+            crate::SetBreakpointsResponse,
+        >(true, true),
+        "setFunctionBreakpoints" => visitor.visit::<
+            crate::SetFunctionBreakpointsArguments,
+            crate::SetFunctionBreakpointsResponse,
+        >(true, true),
+        "setExceptionBreakpoints" => visitor.visit::<
+            crate::SetExceptionBreakpointsArguments,
+            crate::SetExceptionBreakpointsResponse,
+        >(true, false),
+        "dataBreakpointInfo" => visitor.visit::<
+            crate::DataBreakpointInfoArguments,
+            crate::DataBreakpointInfoResponse,
+        >(true, true),
+        "setDataBreakpoints" => visitor.visit::<
+            crate::SetDataBreakpointsArguments,
+            crate::SetDataBreakpointsResponse,
+        >(true, true),
+        "setInstructionBreakpoints" => visitor.visit::<
+            crate::SetInstructionBreakpointsArguments,
+            crate::SetInstructionBreakpointsResponse,
+        >(true, true),
+        "continue" => visitor.visit::<
+            crate::ContinueArguments,
+            crate::ContinueResponse,
+        >(true, true),
+        "next" => visitor.visit::<crate::NextArguments, serde_json::Value>(true, false),
+        "stepIn" => visitor.visit::<crate::StepInArguments, serde_json::Value>(true, false),
+        "stepOut" => visitor.visit::<crate::StepOutArguments, serde_json::Value>(true, false),
+        "stepBack" => visitor.visit::<crate::StepBackArguments, serde_json::Value>(true, false),
+        "reverseContinue" => visitor.visit::<
+            crate::ReverseContinueArguments,
+            serde_json::Value,
+        >(true, false),
+        "restartFrame" => visitor.visit::<
+            crate::RestartFrameArguments,
+            serde_json::Value,
+        >(true, false),
+        "goto" => visitor.visit::<crate::GotoArguments, serde_json::Value>(true, false),
+        "pause" => visitor.visit::<crate::PauseArguments, serde_json::Value>(true, false),
+        "stackTrace" => visitor.visit::<
+            crate::StackTraceArguments,
+            crate::StackTraceResponse,
+        >(true, true),
+        "scopes" => visitor.visit::<crate::ScopesArguments, crate::ScopesResponse>(true, true),
+        "variables" => visitor.visit::<
+            crate::VariablesArguments,
+            crate::VariablesResponse,
+        >(true, true),
+        "setVariable" => visitor.visit::<
+            crate::SetVariableArguments,
+            crate::SetVariableResponse,
+        >(true, true),
+        "source" => visitor.visit::<crate::SourceArguments, crate::SourceResponse>(true, true),
+        "threads" => visitor.visit::<serde_json::Value, crate::ThreadsResponse>(false, true),
+        "terminateThreads" => visitor.visit::<
+            crate::TerminateThreadsArguments,
+            serde_json::Value,
+        >(true, false),
+        "modules" => visitor.visit::<crate::ModulesArguments, crate::ModulesResponse>(true, true),
+        "loadedSources" => visitor.visit::<
+            crate::LoadedSourcesArguments,
+            crate::LoadedSourcesResponse,
+        >(false, true),
+        "evaluate" => visitor.visit::<
+            crate::EvaluateArguments,
+            crate::EvaluateResponse,
+        >(true, true),
+        "setExpression" => visitor.visit::<
+            crate::SetExpressionArguments,
+            crate::SetExpressionResponse,
+        >(true, true),
+        "stepInTargets" => visitor.visit::<
+            crate::StepInTargetsArguments,
+            crate::StepInTargetsResponse,
+        >(true, true),
+        "gotoTargets" => visitor.visit::<
+            crate::GotoTargetsArguments,
+            crate::GotoTargetsResponse,
+        >(true, true),
+        "completions" => visitor.visit::<
+            crate::CompletionsArguments,
+            crate::CompletionsResponse,
+        >(true, true),
+        "exceptionInfo" => visitor.visit::<
+            crate::ExceptionInfoArguments,
+            crate::ExceptionInfoResponse,
+        >(true, true),
+        "readMemory" => visitor.visit::<
+            crate::ReadMemoryArguments,
+            crate::ReadMemoryResponse,
+        >(true, false),
+        "writeMemory" => visitor.visit::<
+            crate::WriteMemoryArguments,
+            crate::WriteMemoryResponse,
+        >(true, false),
+        "disassemble" => visitor.visit::<
+            crate::DisassembleArguments,
+            crate::DisassembleResponse,
+        >(true, false),
+        "locations" => visitor.visit::<
+            crate::LocationsArguments,
+            crate::LocationsResponse,
+        >(true, false),
+        _ => return None,
+    })
+}
@@ -0,0 +1,126 @@
+use schemars::generate::SchemaSettings;
+use schemars::Schema;
+
+/// The JSON Schema of the protocol types, as represented by this crate.
+///
+/// The schema uses the layout of the protocol's own `debugProtocol.json`: each type is a
+/// definition under `definitions`, and types refer to each other with
+/// `#/definitions/<name>`. Responses and events are represented by the schema of their
+/// `body`.
+pub fn protocol_schema() -> Schema {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    crate::types::add_schemas(&mut generator);
+    let definitions = generator.take_definitions(true);
+    schemars::json_schema!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Debug Adapter Protocol",
+        "type": "object",
+        "definitions": definitions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Properties that are required by the protocol, but default to an empty string to work
+    /// around adapters that omit them.
+    const OPTIONAL_WORKAROUNDS: &[(&str, &str)] =
+        &[("Thread", "name"), ("RunInTerminalRequestArguments", "cwd")];
+
+    fn properties(schema: &Value) -> BTreeSet<&str> {
+        schema["properties"]
+            .as_object()
+            .into_iter()
+            .flat_map(|p| p.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn required(schema: &Value) -> BTreeSet<&str> {
+        schema["required"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect()
+    }
+
+    /// The properties and required properties of a protocol definition, including the ones of
+    /// the definitions it extends with `allOf`.
+    fn fields<'a>(protocol: &'a Value, definition: &'a Value) -> [BTreeSet<&'a str>; 2] {
+        let Some(parts) = definition["allOf"].as_array() else {
+            return [properties(definition), required(definition)];
+        };
+        let mut fields = [BTreeSet::new(), BTreeSet::new()];
+        for part in parts {
+            let part = match part["$ref"].as_str() {
+                Some(r) => &protocol["definitions"][r.trim_start_matches("#/definitions/")],
+                None => part,
+            };
+            let [properties, required] = self::fields(protocol, part);
+            fields[0].extend(properties);
+            fields[1].extend(required);
+        }
+        fields
+    }
+
+    /// The object schema of a protocol definition, or of its `body` for responses and events.
+    fn object_schema<'a>(name: &str, definition: &'a Value) -> Option<&'a Value> {
+        if name.ends_with("Response") || name.ends_with("Event") {
+            definition["allOf"].as_array()?.last()?["properties"].get("body")
+        } else {
+            Some(definition)
+        }
+    }
+
+    #[test]
+    fn test_schema_matches_protocol() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../schema.json");
+        let protocol: Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let exported = protocol_schema().to_value();
+
+        let mut compared = 0;
+        for (name, definition) in exported["definitions"].as_object().unwrap() {
+            let Some(expected) = protocol["definitions"]
+                .get(name)
+                .and_then(|d| object_schema(name, d))
+            else {
+                continue;
+            };
+            let [expected_properties, mut expected_required] = fields(&protocol, expected);
+            if expected_properties.is_empty() {
+                continue;
+            }
+            assert_eq!(
+                properties(definition),
+                expected_properties,
+                "properties of {name}"
+            );
+            for (ty, field) in OPTIONAL_WORKAROUNDS {
+                if ty == name {
+                    expected_required.remove(field);
+                }
+            }
+            assert_eq!(
+                required(definition),
+                expected_required,
+                "required properties of {name}"
+            );
+            compared += 1;
+        }
+        assert!(compared > 100, "only {compared} definitions compared");
+
+        let definitions = &exported["definitions"];
+        assert_eq!(definitions["Thread"]["description"], "A Thread");
+        assert!(definitions["StoppedEventReason"]["description"]
+            .as_str()
+            .unwrap()
+            .starts_with("The reason for the event."));
+    }
+}
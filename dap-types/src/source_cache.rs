@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "checksums")]
+use std::{fs, io};
+
+#[cfg(feature = "checksums")]
+use crate::checksum::{self, ChecksumStatus};
+use crate::{Source, SourceArguments, SourceResponse};
+
+/// Source contents fetched with a `source` request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceContent {
+    pub content: String,
+    pub mime_type: Option<String>,
+}
+
+/// How a local file compares with the source the debuggee is running.
+#[cfg(feature = "checksums")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalFileStatus {
+    /// The file matches the checksums reported by the adapter.
+    UpToDate,
+    /// The file differs from what the debuggee runs, e.g. because it was edited after the
+    /// program was built. Breakpoints and stack frames may point at the wrong lines.
+    Stale,
+    /// The adapter did not report checksums that could be verified.
+    Unverified,
+    /// The source has no `path`, or the file can not be read.
+    Unavailable,
+}
+
+/// Cache of source contents that have to be fetched from the adapter.
+///
+/// The protocol requires the contents of a source with a `sourceReference` > 0 to be retrieved
+/// with the `source` request, even if it has a `path`. References are only valid for the
+/// session, so the cache has to be cleared when the session ends.
+#[derive(Debug, Clone, Default)]
+pub struct SourceCache {
+    contents: HashMap<i64, SourceContent>,
+    pending: HashSet<i64>,
+}
+
+impl SourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the contents of `source` have to be fetched with a `source` request
+    /// rather than read from `path`.
+    pub fn needs_fetch(source: &Source) -> bool {
+        source_reference(source).is_some()
+    }
+
+    /// Arguments for a `source` request fetching the contents of `source`, or `None` if the
+    /// source has no reference, or its contents are already cached or being fetched. The request
+    /// is marked as pending until [`SourceCache::on_source_response`] or
+    /// [`SourceCache::on_source_failed`] is called.
+    pub fn request(&mut self, source: &Source) -> Option<SourceArguments> {
+        let reference = source_reference(source)?;
+        if self.contents.contains_key(&reference) || !self.pending.insert(reference) {
+            return None;
+        }
+        Some(SourceArguments::new(reference).source(source.clone()))
+    }
+
+    pub fn on_source_response(&mut self, source_reference: i64, response: SourceResponse) {
+        self.pending.remove(&source_reference);
+        self.contents.insert(
+            source_reference,
+            SourceContent {
+                content: response.content,
+                mime_type: response.mime_type,
+            },
+        );
+    }
+
+    /// Marks a failed `source` request as no longer pending, so that it can be retried.
+    pub fn on_source_failed(&mut self, source_reference: i64) {
+        self.pending.remove(&source_reference);
+    }
+
+    pub fn get(&self, source_reference: i64) -> Option<&SourceContent> {
+        self.contents.get(&source_reference)
+    }
+
+    /// The cached contents of `source`, if it has a reference.
+    pub fn content(&self, source: &Source) -> Option<&SourceContent> {
+        self.get(source_reference(source)?)
+    }
+
+    /// Drops the cached contents of a reference, e.g. after a `loadedSource` event with reason
+    /// `changed`.
+    pub fn invalidate(&mut self, source_reference: i64) {
+        self.contents.remove(&source_reference);
+    }
+
+    pub fn clear(&mut self) {
+        self.contents.clear();
+        self.pending.clear();
+    }
+}
+
+fn source_reference(source: &Source) -> Option<i64> {
+    source.source_reference.filter(|r| *r > 0)
+}
+
+/// Compares the local file at `source.path` with the `checksums` reported by the adapter.
+#[cfg(feature = "checksums")]
+pub fn local_file_status(source: &Source) -> LocalFileStatus {
+    let Some(path) = &source.path else {
+        return LocalFileStatus::Unavailable;
+    };
+    match read_file(path) {
+        Ok((contents, modified)) => {
+            let checksums = source.checksums.as_deref().unwrap_or_default();
+            match checksum::verify(checksums, &contents, modified) {
+                ChecksumStatus::Match => LocalFileStatus::UpToDate,
+                ChecksumStatus::Mismatch => LocalFileStatus::Stale,
+                ChecksumStatus::Unverified => LocalFileStatus::Unverified,
+            }
+        }
+        Err(_) => LocalFileStatus::Unavailable,
+    }
+}
+
+#[cfg(feature = "checksums")]
+fn read_file(path: &str) -> io::Result<(Vec<u8>, Option<std::time::SystemTime>)> {
+    let contents = fs::read(path)?;
+    let modified = fs::metadata(path)?.modified().ok();
+    Ok((contents, modified))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_once() {
+        let mut cache = SourceCache::new();
+        let remote = Source::new()
+            .source_reference(7)
+            .path("/does/not/exist".to_owned());
+        assert_eq!(cache.request(&remote).unwrap().source_reference, 7);
+        assert_eq!(cache.request(&remote), None);
+        cache.on_source_response(
+            7,
+            SourceResponse::new("fn main() {}".to_owned()).mime_type("text/x-rust".to_owned()),
+        );
+        assert_eq!(cache.content(&remote).unwrap().content, "fn main() {}");
+        assert_eq!(cache.request(&Source::new().source_reference(0)), None);
+    }
+
+    #[cfg(feature = "checksums")]
+    #[test]
+    fn test_local_file_status() {
+        use crate::{Checksum, ChecksumAlgorithm};
+
+        let path = std::env::temp_dir().join(format!("dap-types-source-{}", std::process::id()));
+        fs::write(&path, "abc").unwrap();
+        let local = Source::new().path(path.to_string_lossy().into_owned());
+        assert_eq!(local_file_status(&local), LocalFileStatus::Unverified);
+        let local = local.checksums(vec![Checksum::new(
+            ChecksumAlgorithm::Sha1,
+            "a9993e364706816aba3e25717850c26c9cd0d89d".to_owned(),
+        )]);
+        assert_eq!(local_file_status(&local), LocalFileStatus::UpToDate);
+        fs::write(&path, "abcd").unwrap();
+        assert_eq!(local_file_status(&local), LocalFileStatus::Stale);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(local_file_status(&local), LocalFileStatus::Unavailable);
+    }
+}
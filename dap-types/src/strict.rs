@@ -0,0 +1,604 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::events::{visit_event, EventVisitor};
+use crate::lenient::Lenient;
+use crate::messages::{Events, Message, Request, Response};
+use crate::requests::{visit_command, CommandVisitor};
+use crate::ErrorResponse;
+
+/// A type that can check JSON against its definition in the protocol, for testing that an
+/// adapter only sends conforming messages.
+///
+/// The regular deserialization accepts messages that do not follow the protocol: it ignores
+/// unknown properties, keeps unknown values of open enums, accepts values that adapters send
+/// although the protocol does not define them (like a `deemphasize` presentation hint of a
+/// `StackFrame`), defaults a missing `Thread.name` and `RunInTerminalRequestArguments.cwd`, and
+/// accepts negative ids and references. Checking reports all of these as violations.
+pub trait Strict {
+    /// Appends the ways in which `value`, found at `path`, deviates from the protocol.
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>);
+}
+
+/// A deviation from the protocol found by strict checking.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Violation {
+    /// The JSON path of the offending value, like `$.breakpoints[0].line`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Error returned when JSON does not conform to the protocol.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StrictError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for StrictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "message does not conform to the protocol")?;
+        for violation in &self.violations {
+            write!(f, "\n  {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StrictError {}
+
+/// Checks `value` against the protocol's definition of `T`.
+pub fn check<T: Strict>(value: &Value) -> Result<(), StrictError> {
+    let mut violations = Vec::new();
+    T::check(value, "$", &mut violations);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(StrictError { violations })
+    }
+}
+
+/// Deserializes `value`, failing if it does not conform to the protocol.
+///
+/// ```
+/// # use dap_types::{strict, StoppedEvent};
+/// let body = serde_json::json!({ "reason": "goroutine", "threadId": -1 });
+/// let err = strict::from_value::<StoppedEvent>(body).unwrap_err();
+/// assert_eq!(err.violations[0].path, "$.reason");
+/// assert_eq!(err.violations[1].path, "$.threadId");
+/// ```
+pub fn from_value<T: Strict + DeserializeOwned>(value: Value) -> Result<T, StrictError> {
+    check::<T>(&value)?;
+    serde_json::from_value(value).map_err(|err| StrictError {
+        violations: vec![Violation {
+            path: "$".to_owned(),
+            message: err.to_string(),
+        }],
+    })
+}
+
+/// Parses and deserializes `json`, failing if it does not conform to the protocol.
+pub fn from_str<T: Strict + DeserializeOwned>(json: &str) -> Result<T, StrictError> {
+    let value = serde_json::from_str(json).map_err(|err| StrictError {
+        violations: vec![Violation {
+            path: "$".to_owned(),
+            message: err.to_string(),
+        }],
+    })?;
+    from_value(value)
+}
+
+#[doc(hidden)]
+pub fn field_path(path: &str, name: &str) -> String {
+    format!("{path}.{name}")
+}
+
+#[doc(hidden)]
+pub fn check_deserialize<T: DeserializeOwned>(
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<Violation>,
+) {
+    if let Err(err) = T::deserialize(value) {
+        violations.push(Violation {
+            path: path.to_owned(),
+            message: err.to_string(),
+        });
+    }
+}
+
+#[doc(hidden)]
+pub fn check_object(
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<Violation>,
+    fields: &[&str],
+) -> Option<()> {
+    let Some(object) = value.as_object() else {
+        violations.push(Violation {
+            path: path.to_owned(),
+            message: "expected an object".to_owned(),
+        });
+        return None;
+    };
+    for name in object.keys() {
+        if !fields.contains(&name.as_str()) {
+            violations.push(Violation {
+                path: field_path(path, name),
+                message: "unknown field".to_owned(),
+            });
+        }
+    }
+    Some(())
+}
+
+#[doc(hidden)]
+pub fn check_field<T: Strict>(
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<Violation>,
+    name: &str,
+    required: bool,
+) {
+    match value.get(name) {
+        Some(field) => T::check(field, &field_path(path, name), violations),
+        None if required => violations.push(Violation {
+            path: path.to_owned(),
+            message: format!("missing required field `{name}`"),
+        }),
+        None => {}
+    }
+}
+
+/// Implements [`Strict`] for a struct, given the protocol names and types of its fields, or for
+/// a type that is checked by deserializing it. Used by the generated types.
+macro_rules! impl_strict {
+    (
+        $ty:ident {
+            required { $($required:literal: $required_ty:ty,)* }
+            optional { $($optional:literal: $optional_ty:ty,)* }
+        }
+    ) => {
+        impl $crate::strict::Strict for $ty {
+            fn check(
+                value: &serde_json::Value,
+                path: &str,
+                violations: &mut Vec<$crate::strict::Violation>,
+            ) {
+                let fields = [$($required,)* $($optional,)*];
+                if $crate::strict::check_object(value, path, violations, &fields).is_none() {
+                    return;
+                }
+                $(
+                    $crate::strict::check_field::<$required_ty>(
+                        value,
+                        path,
+                        violations,
+                        $required,
+                        true,
+                    );
+                )*
+                $(
+                    $crate::strict::check_field::<$optional_ty>(
+                        value,
+                        path,
+                        violations,
+                        $optional,
+                        false,
+                    );
+                )*
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl $crate::strict::Strict for $ty {
+            fn check(
+                value: &serde_json::Value,
+                path: &str,
+                violations: &mut Vec<$crate::strict::Violation>,
+            ) {
+                $crate::strict::check_deserialize::<Self>(value, path, violations);
+            }
+        }
+    };
+}
+
+pub(crate) use impl_strict;
+
+impl_strict!(bool);
+impl_strict!(u32);
+impl_strict!(u64);
+impl_strict!(i64);
+impl_strict!(String);
+impl_strict!(Value);
+
+impl<T: Strict> Strict for Option<T> {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        if !value.is_null() {
+            T::check(value, path, violations);
+        }
+    }
+}
+
+impl<T: Strict> Strict for Vec<T> {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        let Some(items) = value.as_array() else {
+            violations.push(Violation {
+                path: path.to_owned(),
+                message: "expected an array".to_owned(),
+            });
+            return;
+        };
+        for (i, item) in items.iter().enumerate() {
+            T::check(item, &format!("{path}[{i}]"), violations);
+        }
+    }
+}
+
+/// Checks the `type` of a message, which the envelopes do not require on their own, since they
+/// are serialized without it inside a [`Message`].
+fn check_message_type(value: &Value, path: &str, violations: &mut Vec<Violation>, ty: &str) {
+    match value.get("type") {
+        Some(Value::String(s)) if s == ty => {}
+        Some(_) => violations.push(Violation {
+            path: field_path(path, "type"),
+            message: format!("expected `{ty}`"),
+        }),
+        None => {}
+    }
+}
+
+/// Checks a message's name against the protocol, returning it if it is a string.
+fn check_name<'a>(
+    value: &'a Value,
+    path: &str,
+    violations: &mut Vec<Violation>,
+    field: &str,
+) -> Option<&'a str> {
+    check_field::<String>(value, path, violations, field, true);
+    value.get(field)?.as_str()
+}
+
+/// Checks the `arguments` of a request or the `body` of a response or event as `T`, a payload
+/// the protocol may leave out.
+struct PayloadChecker<'a> {
+    value: &'a Value,
+    path: &'a str,
+    violations: &'a mut Vec<Violation>,
+    field: &'static str,
+}
+
+impl PayloadChecker<'_> {
+    fn check<T: Strict>(self, required: bool) {
+        let path = field_path(self.path, self.field);
+        match self.value.get(self.field) {
+            // `Message` serializes a missing optional payload as `null`.
+            Some(Value::Null) | None if !required => {}
+            Some(Value::Null) => self.violations.push(Violation {
+                path,
+                message: "required field is `null`".to_owned(),
+            }),
+            None => self.violations.push(Violation {
+                path: self.path.to_owned(),
+                message: format!("missing required field `{}`", self.field),
+            }),
+            Some(payload) => T::check(payload, &path, self.violations),
+        }
+    }
+}
+
+impl CommandVisitor for PayloadChecker<'_> {
+    type Output = ();
+
+    fn visit<A, B>(self, arguments_required: bool, body_required: bool)
+    where
+        A: Strict + Lenient,
+        B: Strict + Lenient,
+    {
+        if self.field == "arguments" {
+            self.check::<A>(arguments_required);
+        } else {
+            self.check::<B>(body_required);
+        }
+    }
+}
+
+impl EventVisitor for PayloadChecker<'_> {
+    type Output = ();
+
+    fn visit<B: Strict + Lenient>(self, body_required: bool) {
+        self.check::<B>(body_required);
+    }
+}
+
+/// Checks a message against the protocol, dispatching on its `type`.
+///
+/// ```
+/// # use dap_types::{messages::Message, strict};
+/// let message = serde_json::json!({
+///     "seq": 3,
+///     "type": "event",
+///     "event": "stopped",
+///     "body": { "reason": "breakpoint", "threadId": "1" }
+/// });
+/// let err = strict::check::<Message>(&message).unwrap_err();
+/// assert_eq!(err.violations[0].path, "$.body.threadId");
+/// ```
+impl Strict for Message {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        let Some(ty) = check_name(value, path, violations, "type") else {
+            return;
+        };
+        match ty {
+            "request" => Request::check(value, path, violations),
+            "response" => Response::check(value, path, violations),
+            "event" => {
+                // `Events` has no `seq`, but the protocol requires it of every message.
+                check_field::<u64>(value, path, violations, "seq", true);
+                Events::check(value, path, violations);
+            }
+            _ => violations.push(Violation {
+                path: field_path(path, "type"),
+                message: format!("unknown message type `{ty}`"),
+            }),
+        }
+    }
+}
+
+impl Strict for Request {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        let fields = ["seq", "type", "command", "arguments"];
+        if check_object(value, path, violations, &fields).is_none() {
+            return;
+        }
+        check_field::<u64>(value, path, violations, "seq", true);
+        check_message_type(value, path, violations, "request");
+        let Some(command) = check_name(value, path, violations, "command") else {
+            return;
+        };
+        let checker = PayloadChecker {
+            value,
+            path,
+            violations,
+            field: "arguments",
+        };
+        if visit_command(command, checker).is_none() {
+            violations.push(Violation {
+                path: field_path(path, "command"),
+                message: format!("unknown command `{command}`"),
+            });
+        }
+    }
+}
+
+impl Strict for Response {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        let fields = [
+            "seq",
+            "type",
+            "request_seq",
+            "success",
+            "command",
+            "message",
+            "body",
+        ];
+        if check_object(value, path, violations, &fields).is_none() {
+            return;
+        }
+        check_field::<u64>(value, path, violations, "seq", true);
+        check_message_type(value, path, violations, "response");
+        check_field::<u64>(value, path, violations, "request_seq", true);
+        check_field::<bool>(value, path, violations, "success", true);
+        check_field::<String>(value, path, violations, "message", false);
+        let command = check_name(value, path, violations, "command");
+        let checker = PayloadChecker {
+            value,
+            path,
+            violations,
+            field: "body",
+        };
+        match value.get("success").and_then(Value::as_bool) {
+            Some(true) => {
+                let Some(command) = command else {
+                    return;
+                };
+                if visit_command(command, checker).is_none() {
+                    violations.push(Violation {
+                        path: field_path(path, "command"),
+                        message: format!("unknown command `{command}`"),
+                    });
+                }
+            }
+            Some(false) => checker.check::<ErrorResponse>(true),
+            None => {}
+        }
+    }
+}
+
+impl Strict for Events {
+    fn check(value: &Value, path: &str, violations: &mut Vec<Violation>) {
+        let fields = ["seq", "type", "event", "body"];
+        if check_object(value, path, violations, &fields).is_none() {
+            return;
+        }
+        check_field::<u64>(value, path, violations, "seq", false);
+        check_message_type(value, path, violations, "event");
+        let Some(event) = check_name(value, path, violations, "event") else {
+            return;
+        };
+        let checker = PayloadChecker {
+            value,
+            path,
+            violations,
+            field: "body",
+        };
+        if visit_event(event, checker).is_none() {
+            violations.push(Violation {
+                path: field_path(path, "event"),
+                message: format!("unknown event `{event}`"),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SetBreakpointsResponse, Thread};
+    use serde_json::json;
+
+    #[test]
+    fn test_violations_have_paths() {
+        let response = json!({
+            "breakpoints": [
+                { "verified": true, "line": 3 },
+                { "verified": "yes", "vendorHint": 1 }
+            ]
+        });
+        let err = check::<SetBreakpointsResponse>(&response).unwrap_err();
+        let paths = err
+            .violations
+            .iter()
+            .map(|v| v.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec!["$.breakpoints[1].vendorHint", "$.breakpoints[1].verified"]
+        );
+        assert!(from_value::<SetBreakpointsResponse>(json!({ "breakpoints": [] })).is_ok());
+    }
+
+    #[test]
+    fn test_workarounds_are_violations() {
+        let thread = json!({ "id": -1 });
+        assert!(serde_json::from_value::<Thread>(thread.clone()).is_ok());
+        let err = check::<Thread>(&thread).unwrap_err();
+        assert_eq!(
+            err.violations
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "$.id: invalid value: integer `-1`, expected u64",
+                "$: missing required field `name`"
+            ]
+        );
+
+        let frame = json!({
+            "id": 1,
+            "name": "main",
+            "line": 1,
+            "column": 1,
+            "presentationHint": "deemphasize"
+        });
+        let frame = serde_json::from_value::<crate::StackFrame>(frame).unwrap();
+        assert_eq!(
+            frame.presentation_hint,
+            Some(crate::StackFramePresentationHint::Deemphasize)
+        );
+        let err = check::<crate::StackFrame>(&serde_json::to_value(&frame).unwrap()).unwrap_err();
+        assert_eq!(
+            err.violations[0].to_string(),
+            "$.presentationHint: value `deemphasize` is not defined by the protocol"
+        );
+    }
+
+    #[test]
+    fn test_message_envelopes() {
+        let paths = |message| {
+            check::<Message>(&message)
+                .err()
+                .map(|err| {
+                    err.violations
+                        .into_iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        let request = json!({
+            "seq": 1,
+            "type": "request",
+            "command": "continue",
+            "arguments": { "threadId": 1 }
+        });
+        assert_eq!(paths(request), Vec::<String>::new());
+        let request = json!({ "seq": -1, "type": "request", "command": "continue" });
+        assert_eq!(
+            paths(request),
+            vec![
+                "$.seq: invalid value: integer `-1`, expected u64",
+                "$: missing required field `arguments`",
+            ]
+        );
+        let request = json!({
+            "seq": 1,
+            "type": "request",
+            "command": "continue",
+            "arguments": null
+        });
+        assert_eq!(
+            paths(request),
+            vec!["$.arguments: required field is `null`"]
+        );
+        let response = json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": true,
+            "command": "threads",
+            "body": { "threads": [{ "id": 1 }] }
+        });
+        assert_eq!(
+            paths(response),
+            vec!["$.body.threads[0]: missing required field `name`"]
+        );
+        let response = json!({
+            "seq": 2,
+            "type": "response",
+            "request_seq": 1,
+            "success": false,
+            "command": "threads",
+            "message": "notStopped",
+            "body": { "error": { "id": 1, "format": "not stopped", "url": 3 } }
+        });
+        assert_eq!(
+            paths(response),
+            vec!["$.body.error.url: invalid type: integer `3`, expected a string"]
+        );
+        let event = json!({ "type": "event", "event": "goroutineCreated", "body": {} });
+        assert_eq!(
+            paths(event),
+            vec![
+                "$: missing required field `seq`",
+                "$.event: unknown event `goroutineCreated`",
+            ]
+        );
+        let event = json!({ "seq": 3, "type": "event", "event": "initialized" });
+        assert_eq!(paths(event), Vec::<String>::new());
+        assert_eq!(
+            paths(json!({ "seq": 4, "type": "reverseRequest" })),
+            vec!["$.type: unknown message type `reverseRequest`"]
+        );
+    }
+
+    #[test]
+    fn test_serialized_envelopes_conform() {
+        let request = Request {
+            seq: 1,
+            command: "threads".to_owned(),
+            arguments: None,
+        };
+        let request = serde_json::to_value(Message::Request(request)).unwrap();
+        assert!(check::<Message>(&request).is_ok());
+        let event = serde_json::to_value(Events::Terminated(None)).unwrap();
+        assert!(check::<Events>(&event).is_ok());
+    }
+}
@@ -0,0 +1,189 @@
+/// Implements serialization for an enum of string values that is open to values not defined by
+/// the protocol, given its documentation, variants and their protocol values. Unrecognized values
+/// are kept in the `Other` variant and serialized back unchanged. Used by the generated types.
+///
+/// Values are compared and hashed by their protocol value, so `Other` holding a known value,
+/// which can only be built directly, is equal to the variant of that value.
+///
+/// Variants listed in a trailing `not_in_protocol` block are values that adapters send although
+/// the protocol does not define them for this type. They are handled like the other variants,
+/// except that strict checking reports them.
+///
+/// ```
+/// # use dap_types::StoppedEventReason;
+/// let reason: StoppedEventReason = serde_json::from_str("\"goroutine\"").unwrap();
+/// assert_eq!(reason, StoppedEventReason::Other("goroutine".to_owned()));
+/// assert_eq!(serde_json::to_string(&reason).unwrap(), "\"goroutine\"");
+/// ```
+macro_rules! impl_string_enum {
+    (
+        $(#[doc = $doc:literal])*
+        $ty:ident { $($variant:ident: $value:literal,)* }
+        $(not_in_protocol { $($extra_variant:ident: $extra_value:literal,)* })?
+    ) => {
+        $crate::string_enum::impl_string_enum! {
+            @impl
+            $(#[doc = $doc])*
+            $ty {
+                $($variant: $value,)*
+                $($($extra_variant: $extra_value,)*)?
+            }
+            protocol_values { $($value,)* }
+        }
+    };
+    (
+        @impl
+        $(#[doc = $doc:literal])*
+        $ty:ident { $($variant:ident: $value:literal,)* }
+        protocol_values { $($protocol_value:literal,)* }
+    ) => {
+        impl $ty {
+            /// The value of this variant in the protocol.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($ty::$variant => $value,)*
+                    $ty::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $ty {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $ty::$variant,)*
+                    _ => $ty::Other(value.to_owned()),
+                }
+            }
+        }
+
+        impl From<String> for $ty {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $ty::$variant,)*
+                    _ => $ty::Other(value),
+                }
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl std::hash::Hash for $ty {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state);
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        impl $crate::strict::Strict for $ty {
+            fn check(
+                value: &serde_json::Value,
+                path: &str,
+                violations: &mut Vec<$crate::strict::Violation>,
+            ) {
+                let message = match value.as_str() {
+                    Some($($protocol_value)|*) => return,
+                    Some(other) => format!("value `{other}` is not defined by the protocol"),
+                    None => "expected a string".to_owned(),
+                };
+                violations.push($crate::strict::Violation {
+                    path: path.to_owned(),
+                    message,
+                });
+            }
+        }
+
+        impl $crate::lenient::Lenient for $ty {
+            fn coerce(
+                _value: &mut serde_json::Value,
+                _path: &str,
+                _on_coercion: &mut dyn FnMut(&$crate::lenient::Coercion),
+            ) {
+            }
+
+            fn default_value() -> Option<serde_json::Value> {
+                Some("".into())
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $ty {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                stringify!($ty).into()
+            }
+
+            fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                let mut schema = schemars::json_schema!({
+                    "anyOf": [
+                        { "enum": [$($value),*] },
+                        { "type": "string" }
+                    ]
+                });
+                let doc: &[&str] = &[$($doc),*];
+                if !doc.is_empty() {
+                    let description = doc.iter().map(|line| line.trim()).collect::<Vec<_>>();
+                    schema.insert("description".to_owned(), description.join("\n").into());
+                }
+                schema
+            }
+        }
+    };
+}
+
+pub(crate) use impl_string_enum;
+
+#[cfg(test)]
+mod tests {
+    use crate::{OutputEventCategory, StackFramePresentationHint};
+
+    #[test]
+    fn test_other_values_round_trip() {
+        let category: OutputEventCategory = serde_json::from_str("\"stdout\"").unwrap();
+        assert_eq!(category, OutputEventCategory::Stdout);
+        let hint: StackFramePresentationHint = serde_json::from_str("\"collapsed\"").unwrap();
+        assert_eq!(
+            hint,
+            StackFramePresentationHint::Other("collapsed".to_owned())
+        );
+        assert_eq!(serde_json::to_string(&hint).unwrap(), "\"collapsed\"");
+        assert_eq!(
+            OutputEventCategory::from("important".to_owned()),
+            OutputEventCategory::Important
+        );
+        assert_eq!(OutputEventCategory::Telemetry.to_string(), "telemetry");
+    }
+
+    #[test]
+    fn test_other_with_known_value_is_normalized() {
+        let other = OutputEventCategory::Other("stdout".to_owned());
+        assert_eq!(other, OutputEventCategory::Stdout);
+        assert_eq!(
+            OutputEventCategory::from(other.as_str()),
+            OutputEventCategory::Stdout
+        );
+        let set = std::collections::HashSet::from([other, OutputEventCategory::Stdout]);
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ContinueResponse, ContinuedEvent, StoppedEvent, StoppedEventReason, ThreadEvent,
+    ThreadEventReason, ThreadsResponse,
+};
+
+/// Why a thread is stopped, taken from the `stopped` event that stopped it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StopInfo {
+    pub reason: StoppedEventReason,
+    pub description: Option<String>,
+    pub text: Option<String>,
+    pub hit_breakpoint_ids: Vec<i64>,
+}
+
+impl StopInfo {
+    fn from_event(event: &StoppedEvent) -> Self {
+        StopInfo {
+            reason: event.reason.clone(),
+            description: event.description.clone(),
+            text: event.text.clone(),
+            hit_breakpoint_ids: event.hit_breakpoint_ids.clone().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ThreadStatus {
+    Running,
+    Stopped(StopInfo),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ThreadState {
+    pub id: i64,
+    /// The name from the last `threads` response, or `None` for threads only known from events.
+    pub name: Option<String>,
+    pub status: ThreadStatus,
+}
+
+impl ThreadState {
+    pub fn is_stopped(&self) -> bool {
+        matches!(self.status, ThreadStatus::Stopped(_))
+    }
+}
+
+/// Tracks the threads of a debuggee and whether each of them is running or stopped.
+///
+/// The registry is driven by `thread`, `stopped` and `continued` events, by `threads` and
+/// `continue` responses, and by the stepping requests the client sends. Because thread events
+/// are optional and carry no thread names, the registry reports when the client should send a
+/// `threads` request to bring it up to date (see [`ThreadRegistry::needs_refetch`]).
+#[derive(Debug, Clone, Default)]
+pub struct ThreadRegistry {
+    threads: BTreeMap<i64, ThreadState>,
+    /// Set while all threads are stopped due to a `stopped` event with `allThreadsStopped`, so
+    /// threads first reported by a later `threads` response are known to be stopped as well.
+    all_stopped: Option<StopInfo>,
+    needs_refetch: bool,
+}
+
+impl ThreadRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, thread_id: i64) -> Option<&ThreadState> {
+        self.threads.get(&thread_id)
+    }
+
+    /// All known threads, ordered by id.
+    pub fn threads(&self) -> impl Iterator<Item = &ThreadState> {
+        self.threads.values()
+    }
+
+    pub fn stopped_threads(&self) -> impl Iterator<Item = &ThreadState> {
+        self.threads.values().filter(|t| t.is_stopped())
+    }
+
+    /// Returns whether the thread list may be out of date and a `threads` request should be
+    /// sent. This is the case after a thread was started, and after the debuggee stopped, since
+    /// adapters are not required to send `thread` events.
+    pub fn needs_refetch(&self) -> bool {
+        self.needs_refetch
+    }
+
+    pub fn on_thread_event(&mut self, event: &ThreadEvent) {
+        match event.reason {
+            ThreadEventReason::Started => {
+                let status = match &self.all_stopped {
+                    Some(stop) => ThreadStatus::Stopped(stop.clone()),
+                    None => ThreadStatus::Running,
+                };
+                self.threads.entry(event.thread_id).or_insert(ThreadState {
+                    id: event.thread_id,
+                    name: None,
+                    status,
+                });
+                self.needs_refetch = true;
+            }
+            ThreadEventReason::Exited => {
+                self.threads.remove(&event.thread_id);
+            }
+            ThreadEventReason::Other(_) => self.needs_refetch = true,
+        }
+    }
+
+    /// Replaces the set of threads with the ones in the response, keeping the status of
+    /// threads that were already known.
+    pub fn on_threads_response(&mut self, response: &ThreadsResponse) {
+        let mut threads = BTreeMap::new();
+        for thread in &response.threads {
+            let status = match self.threads.remove(&thread.id) {
+                Some(state) => state.status,
+                None => match &self.all_stopped {
+                    Some(stop) => ThreadStatus::Stopped(stop.clone()),
+                    None => ThreadStatus::Running,
+                },
+            };
+            threads.insert(
+                thread.id,
+                ThreadState {
+                    id: thread.id,
+                    name: Some(thread.name.clone()),
+                    status,
+                },
+            );
+        }
+        self.threads = threads;
+        self.needs_refetch = false;
+    }
+
+    pub fn on_stopped(&mut self, event: &StoppedEvent) {
+        let stop = StopInfo::from_event(event);
+        if event.all_threads_stopped.unwrap_or(false) {
+            for thread in self.threads.values_mut() {
+                thread.status = ThreadStatus::Stopped(stop.clone());
+            }
+            self.all_stopped = Some(stop.clone());
+        }
+        if let Some(thread_id) = event.thread_id {
+            self.threads
+                .entry(thread_id)
+                .or_insert_with(|| ThreadState {
+                    id: thread_id,
+                    name: None,
+                    status: ThreadStatus::Running,
+                })
+                .status = ThreadStatus::Stopped(stop);
+        }
+        self.needs_refetch = true;
+    }
+
+    pub fn on_continued(&mut self, event: &ContinuedEvent) {
+        self.resume(event.thread_id, event.all_threads_continued.unwrap_or(true));
+    }
+
+    /// Applies the response to a `continue` request for `thread_id`.
+    pub fn on_continue_response(&mut self, thread_id: i64, response: &ContinueResponse) {
+        self.resume(thread_id, response.all_threads_continued.unwrap_or(true));
+    }
+
+    /// Records that a stepping request (`next`, `stepIn`, `stepOut`, `stepBack` or
+    /// `reverseContinue`) was sent for `thread_id` with the given `singleThread` flag.
+    ///
+    /// Stepping resumes all suspended threads unless `singleThread` is true, and the flag is only
+    /// honored by adapters that support `supportsSingleThreadExecutionRequests`, so pass `None`
+    /// if the adapter does not.
+    pub fn on_step(&mut self, thread_id: i64, single_thread: Option<bool>) {
+        self.resume(thread_id, !single_thread.unwrap_or(false));
+    }
+
+    /// Forgets all threads, e.g. after the debuggee exited or the session was terminated.
+    pub fn clear(&mut self) {
+        self.threads.clear();
+        self.all_stopped = None;
+        self.needs_refetch = false;
+    }
+
+    fn resume(&mut self, thread_id: i64, all_threads: bool) {
+        if all_threads {
+            for thread in self.threads.values_mut() {
+                thread.status = ThreadStatus::Running;
+            }
+            self.all_stopped = None;
+        } else if let Some(thread) = self.threads.get_mut(&thread_id) {
+            thread.status = ThreadStatus::Running;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Thread;
+
+    #[test]
+    fn test_thread_registry_stop_and_continue() {
+        let mut registry = ThreadRegistry::new();
+        registry.on_threads_response(&ThreadsResponse::new(vec![
+            Thread::new(1, "main".to_owned()),
+            Thread::new(2, "worker".to_owned()),
+        ]));
+        assert!(!registry.needs_refetch());
+
+        registry.on_stopped(
+            &StoppedEvent::new(StoppedEventReason::Breakpoint)
+                .thread_id(1)
+                .all_threads_stopped(true)
+                .hit_breakpoint_ids(vec![3]),
+        );
+        assert_eq!(registry.stopped_threads().count(), 2);
+        assert!(registry.needs_refetch());
+
+        registry.on_step(2, Some(true));
+        assert!(!registry.get(2).unwrap().is_stopped());
+        assert!(registry.get(1).unwrap().is_stopped());
+
+        registry.on_continued(&ContinuedEvent::new(1));
+        assert_eq!(registry.stopped_threads().count(), 0);
+
+        registry.on_thread_event(&ThreadEvent::new(ThreadEventReason::Exited, 2));
+        assert_eq!(
+            registry.threads().map(|t| t.id).collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+}
@@ -1,21 +1,55 @@
 // This file is autogenerated. Do not edit by hand.
 // To regenerate from schema, run `cargo run -p generator`.
 
+#[cfg(feature = "schemars")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// On error (whenever `success` is false), the body can provide more details.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ErrorResponse {
     /// A structured error message.
     #[serde(rename = "error")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub error: Option<Message>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ErrorResponse {
+        required {}
+        optional {
+            error: Message,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ErrorResponse {
+        required {}
+        optional {
+            "error": Message,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ErrorResponse {
+        required {}
+        optional {
+            "error": Message,
+        }
+    }
 }
 
 /// Arguments for `cancel` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct CancelArguments {
     /// The ID (attribute `seq`) of the request to cancel. If missing no request is cancelled.
     /// Both a `requestId` and a `progressId` can be specified in one request.
@@ -29,11 +63,46 @@ pub struct CancelArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub progress_id: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    CancelArguments {
+        required {}
+        optional {
+            request_id: u64,
+            progress_id: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    CancelArguments {
+        required {}
+        optional {
+            "requestId": u64,
+            "progressId": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    CancelArguments {
+        required {}
+        optional {
+            "requestId": u64,
+            "progressId": String,
+        }
+    }
 }
 
 /// The event indicates that the execution of the debuggee has stopped due to some condition.
 /// This can be caused by a breakpoint previously set, a stepping request has completed, by executing a debugger statement etc.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StoppedEvent {
     /// The reason for the event.
     /// For backward compatibility this string is shown in the UI if the `description` attribute is missing (but it must not be translated).
@@ -74,39 +143,99 @@ pub struct StoppedEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub hit_breakpoint_ids: Option<Vec<i64>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StoppedEvent {
+        required {
+            reason: StoppedEventReason,
+        }
+        optional {
+            description: String,
+            thread_id: i64,
+            preserve_focus_hint: bool,
+            text: String,
+            all_threads_stopped: bool,
+            hit_breakpoint_ids: Vec<i64>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StoppedEvent {
+        required {
+            "reason": StoppedEventReason,
+        }
+        optional {
+            "description": String,
+            "threadId": u64,
+            "preserveFocusHint": bool,
+            "text": String,
+            "allThreadsStopped": bool,
+            "hitBreakpointIds": Vec<u64>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StoppedEvent {
+        required {
+            "reason": StoppedEventReason,
+        }
+        optional {
+            "description": String,
+            "threadId": u64,
+            "preserveFocusHint": bool,
+            "text": String,
+            "allThreadsStopped": bool,
+            "hitBreakpointIds": Vec<u64>,
+        }
+    }
 }
 
 /// The reason for the event.
 /// For backward compatibility this string is shown in the UI if the `description` attribute is missing (but it must not be translated).
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum StoppedEventReason {
-    #[serde(rename = "step")]
     Step,
-    #[serde(rename = "breakpoint")]
     Breakpoint,
-    #[serde(rename = "exception")]
     Exception,
-    #[serde(rename = "pause")]
     Pause,
-    #[serde(rename = "entry")]
     Entry,
-    #[serde(rename = "goto")]
     Goto,
-    #[serde(rename = "function breakpoint")]
     FunctionBreakpoint,
-    #[serde(rename = "data breakpoint")]
     DataBreakpoint,
-    #[serde(rename = "instruction breakpoint")]
     InstructionBreakpoint,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// The reason for the event.
+    /// For backward compatibility this string is shown in the UI if the `description` attribute is missing (but it must not be translated).
+    StoppedEventReason {
+        Step: "step",
+        Breakpoint: "breakpoint",
+        Exception: "exception",
+        Pause: "pause",
+        Entry: "entry",
+        Goto: "goto",
+        FunctionBreakpoint: "function breakpoint",
+        DataBreakpoint: "data breakpoint",
+        InstructionBreakpoint: "instruction breakpoint",
+    }
 }
 
 /// The event indicates that the execution of the debuggee has continued.
 /// Please note: a debug adapter is not expected to send this event in response to a request that implies that execution continues, e.g. `launch` or `continue`.
 /// It is only necessary to send a `continued` event if there was no previous request that implied this.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ContinuedEvent {
     /// The thread which was continued.
     #[serde(rename = "threadId")]
@@ -116,18 +245,88 @@ pub struct ContinuedEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub all_threads_continued: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ContinuedEvent {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            all_threads_continued: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ContinuedEvent {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "allThreadsContinued": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ContinuedEvent {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "allThreadsContinued": bool,
+        }
+    }
 }
 
 /// The event indicates that the debuggee has exited and returns its exit code.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExitedEvent {
     /// The exit code returned from the debuggee.
     #[serde(rename = "exitCode")]
     pub exit_code: u64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExitedEvent {
+        required {
+            exit_code: u64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ExitedEvent {
+        required {
+            "exitCode": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExitedEvent {
+        required {
+            "exitCode": u64,
+        }
+        optional {}
+    }
 }
 
 /// The event indicates that debugging of the debuggee has terminated. This does **not** mean that the debuggee itself has exited.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct TerminatedEvent {
     /// A debug adapter may set `restart` to true (or to an arbitrary object) to request that the client restarts the session.
     /// The value is not interpreted by the client and passed unmodified as an attribute `__restart` to the `launch` and `attach` requests.
@@ -135,10 +334,42 @@ pub struct TerminatedEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub restart: Option<serde_json::Value>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    TerminatedEvent {
+        required {}
+        optional {
+            restart: serde_json::Value,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    TerminatedEvent {
+        required {}
+        optional {
+            "restart": serde_json::Value,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    TerminatedEvent {
+        required {}
+        optional {
+            "restart": serde_json::Value,
+        }
+    }
 }
 
 /// The event indicates that a thread has started or exited.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ThreadEvent {
     /// The reason for the event.
     #[serde(rename = "reason")]
@@ -146,22 +377,63 @@ pub struct ThreadEvent {
     /// The identifier of the thread.
     #[serde(rename = "threadId")]
     pub thread_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ThreadEvent {
+        required {
+            reason: ThreadEventReason,
+            thread_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ThreadEvent {
+        required {
+            "reason": ThreadEventReason,
+            "threadId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ThreadEvent {
+        required {
+            "reason": ThreadEventReason,
+            "threadId": u64,
+        }
+        optional {}
+    }
 }
 
 /// The reason for the event.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum ThreadEventReason {
-    #[serde(rename = "started")]
     Started,
-    #[serde(rename = "exited")]
     Exited,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// The reason for the event.
+    ThreadEventReason {
+        Started: "started",
+        Exited: "exited",
+    }
 }
 
 /// The event indicates that the target has produced some output.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct OutputEvent {
     /// The output category. If not specified or if the category is not understood by the client, `console` is assumed.
     #[serde(rename = "category")]
@@ -184,7 +456,7 @@ pub struct OutputEvent {
     #[serde(rename = "variablesReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub variables_reference: Option<u64>,
+    pub variables_reference: Option<i64>,
     /// The source location where the output was produced.
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -211,34 +483,99 @@ pub struct OutputEvent {
     #[serde(rename = "locationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub location_reference: Option<u64>,
+    pub location_reference: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    OutputEvent {
+        required {
+            output: String,
+        }
+        optional {
+            category: OutputEventCategory,
+            group: OutputEventGroup,
+            variables_reference: i64,
+            source: Source,
+            line: u64,
+            column: u64,
+            data: serde_json::Value,
+            location_reference: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    OutputEvent {
+        required {
+            "output": String,
+        }
+        optional {
+            "category": OutputEventCategory,
+            "group": OutputEventGroup,
+            "variablesReference": u64,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "data": serde_json::Value,
+            "locationReference": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    OutputEvent {
+        required {
+            "output": String,
+        }
+        optional {
+            "category": OutputEventCategory,
+            "group": OutputEventGroup,
+            "variablesReference": u64,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "data": serde_json::Value,
+            "locationReference": u64,
+        }
+    }
 }
 
 /// The output category. If not specified or if the category is not understood by the client, `console` is assumed.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum OutputEventCategory {
     /// Show the output in the client's default message UI, e.g. a 'debug console'. This category should only be used for informational output from the debugger (as opposed to the debuggee).
-    #[serde(rename = "console")]
     Console,
     /// A hint for the client to show the output in the client's UI for important and highly visible information, e.g. as a popup notification. This category should only be used for important messages from the debugger (as opposed to the debuggee). Since this category value is a hint, clients might ignore the hint and assume the `console` category.
-    #[serde(rename = "important")]
     Important,
     /// Show the output as normal program output from the debuggee.
-    #[serde(rename = "stdout")]
     Stdout,
     /// Show the output as error program output from the debuggee.
-    #[serde(rename = "stderr")]
     Stderr,
     /// Send the output to telemetry instead of showing it to the user.
-    #[serde(rename = "telemetry")]
     Telemetry,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// The output category. If not specified or if the category is not understood by the client, `console` is assumed.
+    OutputEventCategory {
+        Console: "console",
+        Important: "important",
+        Stdout: "stdout",
+        Stderr: "stderr",
+        Telemetry: "telemetry",
+    }
 }
 
 /// Support for keeping an output log organized by grouping related messages.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum OutputEventGroup {
     /// Start a new group in expanded mode. Subsequent output events are members of the group and should be shown indented.
     /// The `output` attribute becomes the name of the group and is not indented.
@@ -254,8 +591,13 @@ pub enum OutputEventGroup {
     End,
 }
 
+crate::strict::impl_strict! { OutputEventGroup }
+
+crate::lenient::impl_lenient! { OutputEventGroup }
+
 /// The event indicates that some information about a breakpoint has changed.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BreakpointEvent {
     /// The reason for the event.
     #[serde(rename = "reason")]
@@ -263,24 +605,65 @@ pub struct BreakpointEvent {
     /// The `id` attribute is used to find the target breakpoint, the other attributes are used as the new values.
     #[serde(rename = "breakpoint")]
     pub breakpoint: Breakpoint,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    BreakpointEvent {
+        required {
+            reason: BreakpointEventReason,
+            breakpoint: Breakpoint,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    BreakpointEvent {
+        required {
+            "reason": BreakpointEventReason,
+            "breakpoint": Breakpoint,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    BreakpointEvent {
+        required {
+            "reason": BreakpointEventReason,
+            "breakpoint": Breakpoint,
+        }
+        optional {}
+    }
 }
 
 /// The reason for the event.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum BreakpointEventReason {
-    #[serde(rename = "changed")]
     Changed,
-    #[serde(rename = "new")]
     New,
-    #[serde(rename = "removed")]
     Removed,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// The reason for the event.
+    BreakpointEventReason {
+        Changed: "changed",
+        New: "new",
+        Removed: "removed",
+    }
 }
 
 /// The event indicates that some information about a module has changed.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ModuleEvent {
     /// The reason for the event.
     #[serde(rename = "reason")]
@@ -288,10 +671,45 @@ pub struct ModuleEvent {
     /// The new, changed, or removed module. In case of `removed` only the module id is used.
     #[serde(rename = "module")]
     pub module: Module,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ModuleEvent {
+        required {
+            reason: ModuleEventReason,
+            module: Module,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ModuleEvent {
+        required {
+            "reason": ModuleEventReason,
+            "module": Module,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ModuleEvent {
+        required {
+            "reason": ModuleEventReason,
+            "module": Module,
+        }
+        optional {}
+    }
 }
 
 /// The reason for the event.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ModuleEventReason {
     #[serde(rename = "new")]
     New,
@@ -301,8 +719,13 @@ pub enum ModuleEventReason {
     Removed,
 }
 
+crate::strict::impl_strict! { ModuleEventReason }
+
+crate::lenient::impl_lenient! { ModuleEventReason }
+
 /// The event indicates that some source has been added, changed, or removed from the set of all loaded sources.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct LoadedSourceEvent {
     /// The reason for the event.
     #[serde(rename = "reason")]
@@ -310,10 +733,45 @@ pub struct LoadedSourceEvent {
     /// The new, changed, or removed source.
     #[serde(rename = "source")]
     pub source: Source,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    LoadedSourceEvent {
+        required {
+            reason: LoadedSourceEventReason,
+            source: Source,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    LoadedSourceEvent {
+        required {
+            "reason": LoadedSourceEventReason,
+            "source": Source,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    LoadedSourceEvent {
+        required {
+            "reason": LoadedSourceEventReason,
+            "source": Source,
+        }
+        optional {}
+    }
 }
 
 /// The reason for the event.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum LoadedSourceEventReason {
     #[serde(rename = "new")]
     New,
@@ -323,8 +781,13 @@ pub enum LoadedSourceEventReason {
     Removed,
 }
 
+crate::strict::impl_strict! { LoadedSourceEventReason }
+
+crate::lenient::impl_lenient! { LoadedSourceEventReason }
+
 /// The event indicates that the debugger has begun debugging a new process. Either one that it has launched, or one that it has attached to.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ProcessEvent {
     /// The logical name of the process. This is usually the full path to process's executable file. Example: /home/example/myproj/program.js.
     #[serde(rename = "name")]
@@ -349,10 +812,57 @@ pub struct ProcessEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub pointer_size: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ProcessEvent {
+        required {
+            name: String,
+        }
+        optional {
+            system_process_id: u64,
+            is_local_process: bool,
+            start_method: ProcessEventStartMethod,
+            pointer_size: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ProcessEvent {
+        required {
+            "name": String,
+        }
+        optional {
+            "systemProcessId": u64,
+            "isLocalProcess": bool,
+            "startMethod": ProcessEventStartMethod,
+            "pointerSize": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ProcessEvent {
+        required {
+            "name": String,
+        }
+        optional {
+            "systemProcessId": u64,
+            "isLocalProcess": bool,
+            "startMethod": ProcessEventStartMethod,
+            "pointerSize": u64,
+        }
+    }
 }
 
 /// Describes how the debug engine started debugging this process.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ProcessEventStartMethod {
     /// Process was launched under the debugger.
     #[serde(rename = "launch")]
@@ -365,21 +875,58 @@ pub enum ProcessEventStartMethod {
     AttachForSuspendedLaunch,
 }
 
+crate::strict::impl_strict! { ProcessEventStartMethod }
+
+crate::lenient::impl_lenient! { ProcessEventStartMethod }
+
 /// The event indicates that one or more capabilities have changed.
 /// Since the capabilities are dependent on the client and its UI, it might not be possible to change that at random times (or too late).
 /// Consequently this event has a hint characteristic: a client can only be expected to make a 'best effort' in honoring individual capabilities but there are no guarantees.
 /// Only changed capabilities need to be included, all other capabilities keep their values.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct CapabilitiesEvent {
     /// The set of updated capabilities.
     #[serde(rename = "capabilities")]
     pub capabilities: Capabilities,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    CapabilitiesEvent {
+        required {
+            capabilities: Capabilities,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    CapabilitiesEvent {
+        required {
+            "capabilities": Capabilities,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    CapabilitiesEvent {
+        required {
+            "capabilities": Capabilities,
+        }
+        optional {}
+    }
 }
 
 /// The event signals that a long running operation is about to start and provides additional information for the client to set up a corresponding progress and cancellation UI.
 /// The client is free to delay the showing of the UI in order to reduce flicker.
 /// This event should only be sent if the corresponding capability `supportsProgressReporting` is true.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ProgressStartEvent {
     /// An ID that can be used in subsequent `progressUpdate` and `progressEnd` events to make them refer to the same progress reporting.
     /// IDs must be unique within a debug session.
@@ -411,12 +958,62 @@ pub struct ProgressStartEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub percentage: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ProgressStartEvent {
+        required {
+            progress_id: String,
+            title: String,
+        }
+        optional {
+            request_id: u64,
+            cancellable: bool,
+            message: String,
+            percentage: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ProgressStartEvent {
+        required {
+            "progressId": String,
+            "title": String,
+        }
+        optional {
+            "requestId": u64,
+            "cancellable": bool,
+            "message": String,
+            "percentage": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ProgressStartEvent {
+        required {
+            "progressId": String,
+            "title": String,
+        }
+        optional {
+            "requestId": u64,
+            "cancellable": bool,
+            "message": String,
+            "percentage": u64,
+        }
+    }
 }
 
 /// The event signals that the progress reporting needs to be updated with a new message and/or percentage.
 /// The client does not have to update the UI immediately, but the clients needs to keep track of the message and/or percentage values.
 /// This event should only be sent if the corresponding capability `supportsProgressReporting` is true.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ProgressUpdateEvent {
     /// The ID that was introduced in the initial `progressStart` event.
     #[serde(rename = "progressId")]
@@ -431,11 +1028,52 @@ pub struct ProgressUpdateEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub percentage: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ProgressUpdateEvent {
+        required {
+            progress_id: String,
+        }
+        optional {
+            message: String,
+            percentage: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ProgressUpdateEvent {
+        required {
+            "progressId": String,
+        }
+        optional {
+            "message": String,
+            "percentage": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ProgressUpdateEvent {
+        required {
+            "progressId": String,
+        }
+        optional {
+            "message": String,
+            "percentage": u64,
+        }
+    }
 }
 
 /// The event signals the end of the progress reporting with a final message.
 /// This event should only be sent if the corresponding capability `supportsProgressReporting` is true.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ProgressEndEvent {
     /// The ID that was introduced in the initial `ProgressStartEvent`.
     #[serde(rename = "progressId")]
@@ -445,12 +1083,50 @@ pub struct ProgressEndEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub message: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ProgressEndEvent {
+        required {
+            progress_id: String,
+        }
+        optional {
+            message: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ProgressEndEvent {
+        required {
+            "progressId": String,
+        }
+        optional {
+            "message": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ProgressEndEvent {
+        required {
+            "progressId": String,
+        }
+        optional {
+            "message": String,
+        }
+    }
 }
 
 /// This event signals that some state in the debug adapter has changed and requires that the client needs to re-render the data snapshot previously requested.
 /// Debug adapters do not have to emit this event for runtime changes like stopped or thread events because in that case the client refetches the new state anyway. But the event can be used for example to refresh the UI after rendering formatting has changed in the debug adapter.
 /// This event should only be sent if the corresponding capability `supportsInvalidatedEvent` is true.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct InvalidatedEvent {
     /// Set of logical areas that got invalidated. This property has a hint characteristic: a client can only be expected to make a 'best effort' in honoring the areas but there are no guarantees. If this property is missing, empty, or if values are not understood, the client should assume a single value `all`.
     #[serde(rename = "areas")]
@@ -466,27 +1142,103 @@ pub struct InvalidatedEvent {
     #[serde(rename = "stackFrameId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub stack_frame_id: Option<u64>,
+    pub stack_frame_id: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    InvalidatedEvent {
+        required {}
+        optional {
+            areas: Vec<InvalidatedAreas>,
+            thread_id: i64,
+            stack_frame_id: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    InvalidatedEvent {
+        required {}
+        optional {
+            "areas": Vec<InvalidatedAreas>,
+            "threadId": u64,
+            "stackFrameId": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    InvalidatedEvent {
+        required {}
+        optional {
+            "areas": Vec<InvalidatedAreas>,
+            "threadId": u64,
+            "stackFrameId": u64,
+        }
+    }
 }
 
 /// This event indicates that some memory range has been updated. It should only be sent if the corresponding capability `supportsMemoryEvent` is true.
 /// Clients typically react to the event by re-issuing a `readMemory` request if they show the memory identified by the `memoryReference` and if the updated memory range overlaps the displayed range. Clients should not make assumptions how individual memory references relate to each other, so they should not assume that they are part of a single continuous address range and might overlap.
 /// Debug adapters can use this event to indicate that the contents of a memory range has changed due to some other request like `setVariable` or `setExpression`. Debug adapters are not expected to emit this event for each and every memory change of a running program, because that information is typically not available from debuggers and it would flood clients with too many events.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct MemoryEvent {
     /// Memory reference of a memory range that has been updated.
     #[serde(rename = "memoryReference")]
     pub memory_reference: String,
     /// Starting offset in bytes where memory has been updated. Can be negative.
     #[serde(rename = "offset")]
-    pub offset: u64,
+    pub offset: i64,
     /// Number of bytes updated.
     #[serde(rename = "count")]
     pub count: u64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    MemoryEvent {
+        required {
+            memory_reference: String,
+            offset: i64,
+            count: u64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    MemoryEvent {
+        required {
+            "memoryReference": String,
+            "offset": i64,
+            "count": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    MemoryEvent {
+        required {
+            "memoryReference": String,
+            "offset": i64,
+            "count": u64,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `runInTerminal` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct RunInTerminalRequestArguments {
     /// What kind of terminal to launch. Defaults to `integrated` if not specified.
     #[serde(rename = "kind")]
@@ -515,10 +1267,60 @@ pub struct RunInTerminalRequestArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub args_can_be_interpreted_by_shell: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    RunInTerminalRequestArguments {
+        required {
+            cwd: String,
+            args: Vec<String>,
+        }
+        optional {
+            kind: RunInTerminalRequestArgumentsKind,
+            title: String,
+            env: serde_json::Value,
+            args_can_be_interpreted_by_shell: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    RunInTerminalRequestArguments {
+        required {
+            "cwd": String,
+            "args": Vec<String>,
+        }
+        optional {
+            "kind": RunInTerminalRequestArgumentsKind,
+            "title": String,
+            "env": serde_json::Value,
+            "argsCanBeInterpretedByShell": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    RunInTerminalRequestArguments {
+        required {
+            "cwd": String,
+            "args": Vec<String>,
+        }
+        optional {
+            "kind": RunInTerminalRequestArgumentsKind,
+            "title": String,
+            "env": serde_json::Value,
+            "argsCanBeInterpretedByShell": bool,
+        }
+    }
 }
 
 /// What kind of terminal to launch. Defaults to `integrated` if not specified.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum RunInTerminalRequestArgumentsKind {
     #[serde(rename = "integrated")]
     Integrated,
@@ -526,8 +1328,13 @@ pub enum RunInTerminalRequestArgumentsKind {
     External,
 }
 
+crate::strict::impl_strict! { RunInTerminalRequestArgumentsKind }
+
+crate::lenient::impl_lenient! { RunInTerminalRequestArgumentsKind }
+
 /// Response to `runInTerminal` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct RunInTerminalResponse {
     /// The process ID. The value should be less than or equal to 2147483647 (2^31-1).
     #[serde(rename = "processId")]
@@ -539,10 +1346,45 @@ pub struct RunInTerminalResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub shell_process_id: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    RunInTerminalResponse {
+        required {}
+        optional {
+            process_id: u64,
+            shell_process_id: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    RunInTerminalResponse {
+        required {}
+        optional {
+            "processId": u64,
+            "shellProcessId": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    RunInTerminalResponse {
+        required {}
+        optional {
+            "processId": u64,
+            "shellProcessId": u64,
+        }
+    }
 }
 
 /// Arguments for `startDebugging` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StartDebuggingRequestArguments {
     /// Arguments passed to the new debug session. The arguments must only contain properties understood by the `launch` or `attach` requests of the debug adapter and they must not contain any client-specific properties (e.g. `type`) or client-specific features (e.g. substitutable 'variables').
     #[serde(rename = "configuration")]
@@ -550,10 +1392,45 @@ pub struct StartDebuggingRequestArguments {
     /// Indicates whether the new debug session should be started with a `launch` or `attach` request.
     #[serde(rename = "request")]
     pub request: StartDebuggingRequestArgumentsRequest,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StartDebuggingRequestArguments {
+        required {
+            configuration: serde_json::Value,
+            request: StartDebuggingRequestArgumentsRequest,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    StartDebuggingRequestArguments {
+        required {
+            "configuration": serde_json::Value,
+            "request": StartDebuggingRequestArgumentsRequest,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StartDebuggingRequestArguments {
+        required {
+            "configuration": serde_json::Value,
+            "request": StartDebuggingRequestArgumentsRequest,
+        }
+        optional {}
+    }
 }
 
 /// Indicates whether the new debug session should be started with a `launch` or `attach` request.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum StartDebuggingRequestArgumentsRequest {
     #[serde(rename = "launch")]
     Launch,
@@ -561,8 +1438,13 @@ pub enum StartDebuggingRequestArgumentsRequest {
     Attach,
 }
 
+crate::strict::impl_strict! { StartDebuggingRequestArgumentsRequest }
+
+crate::lenient::impl_lenient! { StartDebuggingRequestArgumentsRequest }
+
 /// Arguments for `initialize` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct InitializeRequestArguments {
     /// The ID of the client using this adapter.
     #[serde(rename = "clientID")]
@@ -647,26 +1529,142 @@ pub struct InitializeRequestArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub supports_ansistyling: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    InitializeRequestArguments {
+        required {
+            adapter_id: String,
+        }
+        optional {
+            client_id: String,
+            client_name: String,
+            locale: String,
+            lines_start_at1: bool,
+            columns_start_at1: bool,
+            path_format: InitializeRequestArgumentsPathFormat,
+            supports_variable_type: bool,
+            supports_variable_paging: bool,
+            supports_run_in_terminal_request: bool,
+            supports_memory_references: bool,
+            supports_progress_reporting: bool,
+            supports_invalidated_event: bool,
+            supports_memory_event: bool,
+            supports_args_can_be_interpreted_by_shell: bool,
+            supports_start_debugging_request: bool,
+            supports_ansistyling: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    InitializeRequestArguments {
+        required {
+            "adapterID": String,
+        }
+        optional {
+            "clientID": String,
+            "clientName": String,
+            "locale": String,
+            "linesStartAt1": bool,
+            "columnsStartAt1": bool,
+            "pathFormat": InitializeRequestArgumentsPathFormat,
+            "supportsVariableType": bool,
+            "supportsVariablePaging": bool,
+            "supportsRunInTerminalRequest": bool,
+            "supportsMemoryReferences": bool,
+            "supportsProgressReporting": bool,
+            "supportsInvalidatedEvent": bool,
+            "supportsMemoryEvent": bool,
+            "supportsArgsCanBeInterpretedByShell": bool,
+            "supportsStartDebuggingRequest": bool,
+            "supportsANSIStyling": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    InitializeRequestArguments {
+        required {
+            "adapterID": String,
+        }
+        optional {
+            "clientID": String,
+            "clientName": String,
+            "locale": String,
+            "linesStartAt1": bool,
+            "columnsStartAt1": bool,
+            "pathFormat": InitializeRequestArgumentsPathFormat,
+            "supportsVariableType": bool,
+            "supportsVariablePaging": bool,
+            "supportsRunInTerminalRequest": bool,
+            "supportsMemoryReferences": bool,
+            "supportsProgressReporting": bool,
+            "supportsInvalidatedEvent": bool,
+            "supportsMemoryEvent": bool,
+            "supportsArgsCanBeInterpretedByShell": bool,
+            "supportsStartDebuggingRequest": bool,
+            "supportsANSIStyling": bool,
+        }
+    }
 }
 
 /// Determines in what format paths are specified. The default is `path`, which is the native format.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum InitializeRequestArgumentsPathFormat {
-    #[serde(rename = "path")]
     Path,
-    #[serde(rename = "uri")]
     Uri,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// Determines in what format paths are specified. The default is `path`, which is the native format.
+    InitializeRequestArgumentsPathFormat {
+        Path: "path",
+        Uri: "uri",
+    }
 }
 
 /// Arguments for `configurationDone` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
-pub struct ConfigurationDoneArguments {}
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ConfigurationDoneArguments {
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ConfigurationDoneArguments {
+        required {}
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ConfigurationDoneArguments {
+        required {}
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ConfigurationDoneArguments {
+        required {}
+        optional {}
+    }
+}
 
 /// Arguments for `disconnect` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DisconnectArguments {
     /// A value of true indicates that this `disconnect` request is part of a restart sequence.
     #[serde(rename = "restart")]
@@ -687,20 +1685,90 @@ pub struct DisconnectArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub suspend_debuggee: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DisconnectArguments {
+        required {}
+        optional {
+            restart: bool,
+            terminate_debuggee: bool,
+            suspend_debuggee: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DisconnectArguments {
+        required {}
+        optional {
+            "restart": bool,
+            "terminateDebuggee": bool,
+            "suspendDebuggee": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DisconnectArguments {
+        required {}
+        optional {
+            "restart": bool,
+            "terminateDebuggee": bool,
+            "suspendDebuggee": bool,
+        }
+    }
 }
 
 /// Arguments for `terminate` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct TerminateArguments {
     /// A value of true indicates that this `terminate` request is part of a restart sequence.
     #[serde(rename = "restart")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub restart: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    TerminateArguments {
+        required {}
+        optional {
+            restart: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    TerminateArguments {
+        required {}
+        optional {
+            "restart": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    TerminateArguments {
+        required {}
+        optional {
+            "restart": bool,
+        }
+    }
 }
 
 /// Arguments for `breakpointLocations` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BreakpointLocationsArguments {
     /// The source location of the breakpoints; either `source.path` or `source.sourceReference` must be specified.
     #[serde(rename = "source")]
@@ -723,19 +1791,98 @@ pub struct BreakpointLocationsArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub end_column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    BreakpointLocationsArguments {
+        required {
+            source: Source,
+            line: u64,
+        }
+        optional {
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    BreakpointLocationsArguments {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    BreakpointLocationsArguments {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
 }
 
 /// Response to `breakpointLocations` request.
 /// Contains possible locations for source breakpoints.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BreakpointLocationsResponse {
     /// Sorted set of possible breakpoint locations.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<BreakpointLocation>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    BreakpointLocationsResponse {
+        required {
+            breakpoints: Vec<BreakpointLocation>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    BreakpointLocationsResponse {
+        required {
+            "breakpoints": Vec<BreakpointLocation>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    BreakpointLocationsResponse {
+        required {
+            "breakpoints": Vec<BreakpointLocation>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `setBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetBreakpointsArguments {
     /// The source location of the breakpoints; either `source.path` or `source.sourceReference` must be specified.
     #[serde(rename = "source")]
@@ -755,6 +1902,49 @@ pub struct SetBreakpointsArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub source_modified: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetBreakpointsArguments {
+        required {
+            source: Source,
+        }
+        optional {
+            breakpoints: Vec<SourceBreakpoint>,
+            lines: Vec<u64>,
+            source_modified: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetBreakpointsArguments {
+        required {
+            "source": Source,
+        }
+        optional {
+            "breakpoints": Vec<SourceBreakpoint>,
+            "lines": Vec<u64>,
+            "sourceModified": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetBreakpointsArguments {
+        required {
+            "source": Source,
+        }
+        optional {
+            "breakpoints": Vec<SourceBreakpoint>,
+            "lines": Vec<u64>,
+            "sourceModified": bool,
+        }
+    }
 }
 
 /// Response to `setBreakpoints` request.
@@ -763,32 +1953,129 @@ pub struct SetBreakpointsArguments {
 /// The breakpoints returned are in the same order as the elements of the `breakpoints`
 /// (or the deprecated `lines`) array in the arguments.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetBreakpointsResponse {
     /// Information about the breakpoints.
     /// The array elements are in the same order as the elements of the `breakpoints` (or the deprecated `lines`) array in the arguments.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<Breakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetBreakpointsResponse {
+        required {
+            breakpoints: Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `setFunctionBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetFunctionBreakpointsArguments {
     /// The function names of the breakpoints.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<FunctionBreakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetFunctionBreakpointsArguments {
+        required {
+            breakpoints: Vec<FunctionBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetFunctionBreakpointsArguments {
+        required {
+            "breakpoints": Vec<FunctionBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetFunctionBreakpointsArguments {
+        required {
+            "breakpoints": Vec<FunctionBreakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Response to `setFunctionBreakpoints` request.
 /// Returned is information about each breakpoint created by this request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetFunctionBreakpointsResponse {
     /// Information about the breakpoints. The array elements correspond to the elements of the `breakpoints` array.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<Breakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetFunctionBreakpointsResponse {
+        required {
+            breakpoints: Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetFunctionBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetFunctionBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `setExceptionBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetExceptionBreakpointsArguments {
     /// Set of exception filters specified by their ID. The set of all possible exception filters is defined by the `exceptionBreakpointFilters` capability. The `filter` and `filterOptions` sets are additive.
     #[serde(rename = "filters")]
@@ -804,13 +2091,54 @@ pub struct SetExceptionBreakpointsArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub exception_options: Option<Vec<ExceptionOptions>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetExceptionBreakpointsArguments {
+        required {
+            filters: Vec<String>,
+        }
+        optional {
+            filter_options: Vec<ExceptionFilterOptions>,
+            exception_options: Vec<ExceptionOptions>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetExceptionBreakpointsArguments {
+        required {
+            "filters": Vec<String>,
+        }
+        optional {
+            "filterOptions": Vec<ExceptionFilterOptions>,
+            "exceptionOptions": Vec<ExceptionOptions>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetExceptionBreakpointsArguments {
+        required {
+            "filters": Vec<String>,
+        }
+        optional {
+            "filterOptions": Vec<ExceptionFilterOptions>,
+            "exceptionOptions": Vec<ExceptionOptions>,
+        }
+    }
 }
 
 /// Response to `setExceptionBreakpoints` request.
 /// The response contains an array of `Breakpoint` objects with information about each exception breakpoint or filter. The `Breakpoint` objects are in the same order as the elements of the `filters`, `filterOptions`, `exceptionOptions` arrays given as arguments. If both `filters` and `filterOptions` are given, the returned array must start with `filters` information first, followed by `filterOptions` information.
 /// The `verified` property of a `Breakpoint` object signals whether the exception breakpoint or filter could be successfully created and whether the condition is valid. In case of an error the `message` property explains the problem. The `id` property can be used to introduce a unique ID for the exception breakpoint or filter so that it can be updated subsequently by sending breakpoint events.
 /// For backward compatibility both the `breakpoints` array and the enclosing `body` are optional. If these elements are missing a client is not able to show problems for individual exception breakpoints or filters.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetExceptionBreakpointsResponse {
     /// Information about the exception breakpoints or filters.
     /// The breakpoints returned are in the same order as the elements of the `filters`, `filterOptions`, `exceptionOptions` arrays in the arguments. If both `filters` and `filterOptions` are given, the returned array must start with `filters` information first, followed by `filterOptions` information.
@@ -818,16 +2146,48 @@ pub struct SetExceptionBreakpointsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub breakpoints: Option<Vec<Breakpoint>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetExceptionBreakpointsResponse {
+        required {}
+        optional {
+            breakpoints: Vec<Breakpoint>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetExceptionBreakpointsResponse {
+        required {}
+        optional {
+            "breakpoints": Vec<Breakpoint>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetExceptionBreakpointsResponse {
+        required {}
+        optional {
+            "breakpoints": Vec<Breakpoint>,
+        }
+    }
 }
 
 /// Arguments for `dataBreakpointInfo` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DataBreakpointInfoArguments {
     /// Reference to the variable container if the data breakpoint is requested for a child of the container. The `variablesReference` must have been obtained in the current suspended state. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub variables_reference: Option<u64>,
+    pub variables_reference: Option<i64>,
     /// The name of the variable's child to obtain data breakpoint information for.
     /// If `variablesReference` isn't specified, this can be an expression, or an address if `asAddress` is also true.
     #[serde(rename = "name")]
@@ -836,7 +2196,7 @@ pub struct DataBreakpointInfoArguments {
     #[serde(rename = "frameId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub frame_id: Option<u64>,
+    pub frame_id: Option<i64>,
     /// If specified, a debug adapter should return information for the range of memory extending `bytes` number of bytes from the address or variable specified by `name`. Breakpoints set using the resulting data ID should pause on data access anywhere within that range.
     ///
     /// Clients may set this property only if the `supportsDataBreakpointBytes` capability is true.
@@ -857,13 +2217,64 @@ pub struct DataBreakpointInfoArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub mode: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DataBreakpointInfoArguments {
+        required {
+            name: String,
+        }
+        optional {
+            variables_reference: i64,
+            frame_id: i64,
+            bytes: u64,
+            as_address: bool,
+            mode: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DataBreakpointInfoArguments {
+        required {
+            "name": String,
+        }
+        optional {
+            "variablesReference": u64,
+            "frameId": u64,
+            "bytes": u64,
+            "asAddress": bool,
+            "mode": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DataBreakpointInfoArguments {
+        required {
+            "name": String,
+        }
+        optional {
+            "variablesReference": u64,
+            "frameId": u64,
+            "bytes": u64,
+            "asAddress": bool,
+            "mode": String,
+        }
+    }
 }
 
 /// Response to `dataBreakpointInfo` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DataBreakpointInfoResponse {
     /// An identifier for the data on which a data breakpoint can be registered with the `setDataBreakpoints` request or null if no data breakpoint is available. If a `variablesReference` or `frameId` is passed, the `dataId` is valid in the current suspended state, otherwise it's valid indefinitely. See 'Lifetime of Object References' in the Overview section for details. Breakpoints set using the `dataId` in the `setDataBreakpoints` request may outlive the lifetime of the associated `dataId`.
     #[serde(rename = "dataId")]
+    #[cfg_attr(feature = "schemars", schemars(required))]
     pub data_id: Option<String>,
     /// UI string that describes on what data the breakpoint is set on or why a data breakpoint is not available.
     #[serde(rename = "description")]
@@ -878,43 +2289,215 @@ pub struct DataBreakpointInfoResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub can_persist: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DataBreakpointInfoResponse {
+        required {
+            data_id: Option<String>,
+            description: String,
+        }
+        optional {
+            access_types: Vec<DataBreakpointAccessType>,
+            can_persist: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DataBreakpointInfoResponse {
+        required {
+            "dataId": Option<String>,
+            "description": String,
+        }
+        optional {
+            "accessTypes": Vec<DataBreakpointAccessType>,
+            "canPersist": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DataBreakpointInfoResponse {
+        required {
+            "dataId": Option<String>,
+            "description": String,
+        }
+        optional {
+            "accessTypes": Vec<DataBreakpointAccessType>,
+            "canPersist": bool,
+        }
+    }
 }
 
 /// Arguments for `setDataBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetDataBreakpointsArguments {
     /// The contents of this array replaces all existing data breakpoints. An empty array clears all data breakpoints.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<DataBreakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetDataBreakpointsArguments {
+        required {
+            breakpoints: Vec<DataBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetDataBreakpointsArguments {
+        required {
+            "breakpoints": Vec<DataBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetDataBreakpointsArguments {
+        required {
+            "breakpoints": Vec<DataBreakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Response to `setDataBreakpoints` request.
 /// Returned is information about each breakpoint created by this request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetDataBreakpointsResponse {
     /// Information about the data breakpoints. The array elements correspond to the elements of the input argument `breakpoints` array.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<Breakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetDataBreakpointsResponse {
+        required {
+            breakpoints: Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetDataBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetDataBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `setInstructionBreakpoints` request
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetInstructionBreakpointsArguments {
     /// The instruction references of the breakpoints
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<InstructionBreakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetInstructionBreakpointsArguments {
+        required {
+            breakpoints: Vec<InstructionBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetInstructionBreakpointsArguments {
+        required {
+            "breakpoints": Vec<InstructionBreakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetInstructionBreakpointsArguments {
+        required {
+            "breakpoints": Vec<InstructionBreakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Response to `setInstructionBreakpoints` request
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetInstructionBreakpointsResponse {
     /// Information about the breakpoints. The array elements correspond to the elements of the `breakpoints` array.
     #[serde(rename = "breakpoints")]
     pub breakpoints: Vec<Breakpoint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetInstructionBreakpointsResponse {
+        required {
+            breakpoints: Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    SetInstructionBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetInstructionBreakpointsResponse {
+        required {
+            "breakpoints": Vec<Breakpoint>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `continue` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ContinueArguments {
     /// Specifies the active thread. If the debug adapter supports single thread execution (see `supportsSingleThreadExecutionRequests`) and the argument `singleThread` is true, only the thread with this ID is resumed.
     #[serde(rename = "threadId")]
@@ -924,20 +2507,90 @@ pub struct ContinueArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub single_thread: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ContinueArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ContinueArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ContinueArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+        }
+    }
 }
 
 /// Response to `continue` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ContinueResponse {
     /// If omitted or set to `true`, this response signals to the client that all threads have been resumed. The value `false` indicates that not all threads were resumed.
     #[serde(rename = "allThreadsContinued")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub all_threads_continued: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ContinueResponse {
+        required {}
+        optional {
+            all_threads_continued: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ContinueResponse {
+        required {}
+        optional {
+            "allThreadsContinued": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ContinueResponse {
+        required {}
+        optional {
+            "allThreadsContinued": bool,
+        }
+    }
 }
 
 /// Arguments for `next` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct NextArguments {
     /// Specifies the thread for which to resume execution for one step (of the given granularity).
     #[serde(rename = "threadId")]
@@ -952,10 +2605,51 @@ pub struct NextArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub granularity: Option<SteppingGranularity>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    NextArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+            granularity: SteppingGranularity,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    NextArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    NextArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
 }
 
 /// Arguments for `stepIn` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepInArguments {
     /// Specifies the thread for which to resume execution for one step-into (of the given granularity).
     #[serde(rename = "threadId")]
@@ -969,16 +2663,60 @@ pub struct StepInArguments {
     #[serde(rename = "targetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub target_id: Option<u64>,
+    pub target_id: Option<i64>,
     /// Stepping granularity. If no granularity is specified, a granularity of `statement` is assumed.
     #[serde(rename = "granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub granularity: Option<SteppingGranularity>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepInArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+            target_id: i64,
+            granularity: SteppingGranularity,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StepInArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "targetId": u64,
+            "granularity": SteppingGranularity,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepInArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "targetId": u64,
+            "granularity": SteppingGranularity,
+        }
+    }
 }
 
 /// Arguments for `stepOut` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepOutArguments {
     /// Specifies the thread for which to resume execution for one step-out (of the given granularity).
     #[serde(rename = "threadId")]
@@ -993,10 +2731,51 @@ pub struct StepOutArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub granularity: Option<SteppingGranularity>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepOutArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+            granularity: SteppingGranularity,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StepOutArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepOutArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
 }
 
 /// Arguments for `stepBack` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepBackArguments {
     /// Specifies the thread for which to resume execution for one step backwards (of the given granularity).
     #[serde(rename = "threadId")]
@@ -1011,10 +2790,51 @@ pub struct StepBackArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub granularity: Option<SteppingGranularity>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepBackArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+            granularity: SteppingGranularity,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StepBackArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepBackArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+            "granularity": SteppingGranularity,
+        }
+    }
 }
 
 /// Arguments for `reverseContinue` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ReverseContinueArguments {
     /// Specifies the active thread. If the debug adapter supports single thread execution (see `supportsSingleThreadExecutionRequests`) and the `singleThread` argument is true, only the thread with this ID is resumed.
     #[serde(rename = "threadId")]
@@ -1024,37 +2844,174 @@ pub struct ReverseContinueArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub single_thread: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ReverseContinueArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            single_thread: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ReverseContinueArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ReverseContinueArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "singleThread": bool,
+        }
+    }
 }
 
 /// Arguments for `restartFrame` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct RestartFrameArguments {
     /// Restart the stack frame identified by `frameId`. The `frameId` must have been obtained in the current suspended state. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "frameId")]
-    pub frame_id: u64,
+    pub frame_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    RestartFrameArguments {
+        required {
+            frame_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    RestartFrameArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    RestartFrameArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `goto` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct GotoArguments {
     /// Set the goto target for this thread.
     #[serde(rename = "threadId")]
     pub thread_id: i64,
     /// The location where the debuggee will continue to run.
     #[serde(rename = "targetId")]
-    pub target_id: u64,
+    pub target_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    GotoArguments {
+        required {
+            thread_id: i64,
+            target_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    GotoArguments {
+        required {
+            "threadId": u64,
+            "targetId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    GotoArguments {
+        required {
+            "threadId": u64,
+            "targetId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `pause` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct PauseArguments {
     /// Pause execution for this thread.
     #[serde(rename = "threadId")]
     pub thread_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    PauseArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    PauseArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    PauseArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `stackTrace` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StackTraceArguments {
     /// Retrieve the stacktrace for this thread.
     #[serde(rename = "threadId")]
@@ -1075,10 +3032,54 @@ pub struct StackTraceArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub format: Option<StackFrameFormat>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StackTraceArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {
+            start_frame: u64,
+            levels: u64,
+            format: StackFrameFormat,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StackTraceArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "startFrame": u64,
+            "levels": u64,
+            "format": StackFrameFormat,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StackTraceArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {
+            "startFrame": u64,
+            "levels": u64,
+            "format": StackFrameFormat,
+        }
+    }
 }
 
 /// Response to `stackTrace` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StackTraceResponse {
     /// The frames of the stack frame. If the array has length zero, there are no stack frames available.
     /// This means that there is no location information available.
@@ -1089,30 +3090,132 @@ pub struct StackTraceResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub total_frames: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StackTraceResponse {
+        required {
+            stack_frames: Vec<StackFrame>,
+        }
+        optional {
+            total_frames: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StackTraceResponse {
+        required {
+            "stackFrames": Vec<StackFrame>,
+        }
+        optional {
+            "totalFrames": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StackTraceResponse {
+        required {
+            "stackFrames": Vec<StackFrame>,
+        }
+        optional {
+            "totalFrames": u64,
+        }
+    }
 }
 
 /// Arguments for `scopes` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ScopesArguments {
     /// Retrieve the scopes for the stack frame identified by `frameId`. The `frameId` must have been obtained in the current suspended state. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "frameId")]
-    pub frame_id: u64,
+    pub frame_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ScopesArguments {
+        required {
+            frame_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ScopesArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ScopesArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Response to `scopes` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ScopesResponse {
     /// The scopes of the stack frame. If the array has length zero, there are no scopes available.
     #[serde(rename = "scopes")]
     pub scopes: Vec<Scope>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ScopesResponse {
+        required {
+            scopes: Vec<Scope>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ScopesResponse {
+        required {
+            "scopes": Vec<Scope>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ScopesResponse {
+        required {
+            "scopes": Vec<Scope>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `variables` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct VariablesArguments {
     /// The variable for which to retrieve its children. The `variablesReference` must have been obtained in the current suspended state. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
-    pub variables_reference: u64,
+    pub variables_reference: i64,
     /// Filter to limit the child variables to either named or indexed. If omitted, both types are fetched.
     #[serde(rename = "filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1136,10 +3239,57 @@ pub struct VariablesArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub format: Option<ValueFormat>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    VariablesArguments {
+        required {
+            variables_reference: i64,
+        }
+        optional {
+            filter: VariablesArgumentsFilter,
+            start: u64,
+            count: u64,
+            format: ValueFormat,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    VariablesArguments {
+        required {
+            "variablesReference": u64,
+        }
+        optional {
+            "filter": VariablesArgumentsFilter,
+            "start": u64,
+            "count": u64,
+            "format": ValueFormat,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    VariablesArguments {
+        required {
+            "variablesReference": u64,
+        }
+        optional {
+            "filter": VariablesArgumentsFilter,
+            "start": u64,
+            "count": u64,
+            "format": ValueFormat,
+        }
+    }
 }
 
 /// Filter to limit the child variables to either named or indexed. If omitted, both types are fetched.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum VariablesArgumentsFilter {
     #[serde(rename = "indexed")]
     Indexed,
@@ -1147,20 +3297,57 @@ pub enum VariablesArgumentsFilter {
     Named,
 }
 
+crate::strict::impl_strict! { VariablesArgumentsFilter }
+
+crate::lenient::impl_lenient! { VariablesArgumentsFilter }
+
 /// Response to `variables` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct VariablesResponse {
     /// All (or a range) of variables for the given variable reference.
     #[serde(rename = "variables")]
     pub variables: Vec<Variable>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    VariablesResponse {
+        required {
+            variables: Vec<Variable>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    VariablesResponse {
+        required {
+            "variables": Vec<Variable>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    VariablesResponse {
+        required {
+            "variables": Vec<Variable>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `setVariable` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetVariableArguments {
     /// The reference of the variable container. The `variablesReference` must have been obtained in the current suspended state. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
-    pub variables_reference: u64,
+    pub variables_reference: i64,
     /// The name of the variable in the container.
     #[serde(rename = "name")]
     pub name: String,
@@ -1172,10 +3359,54 @@ pub struct SetVariableArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub format: Option<ValueFormat>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetVariableArguments {
+        required {
+            variables_reference: i64,
+            name: String,
+            value: String,
+        }
+        optional {
+            format: ValueFormat,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetVariableArguments {
+        required {
+            "variablesReference": u64,
+            "name": String,
+            "value": String,
+        }
+        optional {
+            "format": ValueFormat,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetVariableArguments {
+        required {
+            "variablesReference": u64,
+            "name": String,
+            "value": String,
+        }
+        optional {
+            "format": ValueFormat,
+        }
+    }
 }
 
 /// Response to `setVariable` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetVariableResponse {
     /// The new value of the variable.
     #[serde(rename = "value")]
@@ -1191,7 +3422,7 @@ pub struct SetVariableResponse {
     #[serde(rename = "variablesReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub variables_reference: Option<u64>,
+    pub variables_reference: Option<i64>,
     /// The number of named child variables.
     /// The client can use this information to present the variables in a paged UI and fetch them in chunks.
     /// The value should be less than or equal to 2147483647 (2^31-1).
@@ -1219,11 +3450,64 @@ pub struct SetVariableResponse {
     #[serde(rename = "valueLocationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub value_location_reference: Option<u64>,
+    pub value_location_reference: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetVariableResponse {
+        required {
+            value: String,
+        }
+        optional {
+            type_: String,
+            variables_reference: i64,
+            named_variables: u64,
+            indexed_variables: u64,
+            memory_reference: String,
+            value_location_reference: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetVariableResponse {
+        required {
+            "value": String,
+        }
+        optional {
+            "type": String,
+            "variablesReference": u64,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetVariableResponse {
+        required {
+            "value": String,
+        }
+        optional {
+            "type": String,
+            "variablesReference": u64,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
 }
 
 /// Arguments for `source` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SourceArguments {
     /// Specifies the source content to load. Either `source.path` or `source.sourceReference` must be specified.
     #[serde(rename = "source")]
@@ -1233,11 +3517,49 @@ pub struct SourceArguments {
     /// The reference to the source. This is the same as `source.sourceReference`.
     /// This is provided for backward compatibility since old clients do not understand the `source` attribute.
     #[serde(rename = "sourceReference")]
-    pub source_reference: u64,
+    pub source_reference: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SourceArguments {
+        required {
+            source_reference: i64,
+        }
+        optional {
+            source: Source,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SourceArguments {
+        required {
+            "sourceReference": u64,
+        }
+        optional {
+            "source": Source,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SourceArguments {
+        required {
+            "sourceReference": u64,
+        }
+        optional {
+            "source": Source,
+        }
+    }
 }
 
 /// Response to `source` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SourceResponse {
     /// Content of the source reference.
     #[serde(rename = "content")]
@@ -1247,28 +3569,130 @@ pub struct SourceResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub mime_type: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SourceResponse {
+        required {
+            content: String,
+        }
+        optional {
+            mime_type: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SourceResponse {
+        required {
+            "content": String,
+        }
+        optional {
+            "mimeType": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SourceResponse {
+        required {
+            "content": String,
+        }
+        optional {
+            "mimeType": String,
+        }
+    }
 }
 
 /// Response to `threads` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ThreadsResponse {
     /// All threads.
     #[serde(rename = "threads")]
     pub threads: Vec<Thread>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ThreadsResponse {
+        required {
+            threads: Vec<Thread>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ThreadsResponse {
+        required {
+            "threads": Vec<Thread>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ThreadsResponse {
+        required {
+            "threads": Vec<Thread>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `terminateThreads` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct TerminateThreadsArguments {
     /// Ids of threads to be terminated.
     #[serde(rename = "threadIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub thread_ids: Option<Vec<i64>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    TerminateThreadsArguments {
+        required {}
+        optional {
+            thread_ids: Vec<i64>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    TerminateThreadsArguments {
+        required {}
+        optional {
+            "threadIds": Vec<u64>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    TerminateThreadsArguments {
+        required {}
+        optional {
+            "threadIds": Vec<u64>,
+        }
+    }
 }
 
 /// Arguments for `modules` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ModulesArguments {
     /// The index of the first module to return; if omitted modules start at 0.
     #[serde(rename = "startModule")]
@@ -1280,10 +3704,45 @@ pub struct ModulesArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub module_count: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ModulesArguments {
+        required {}
+        optional {
+            start_module: u64,
+            module_count: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ModulesArguments {
+        required {}
+        optional {
+            "startModule": u64,
+            "moduleCount": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ModulesArguments {
+        required {}
+        optional {
+            "startModule": u64,
+            "moduleCount": u64,
+        }
+    }
 }
 
 /// Response to `modules` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ModulesResponse {
     /// All modules or range of modules.
     #[serde(rename = "modules")]
@@ -1293,22 +3752,119 @@ pub struct ModulesResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub total_modules: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ModulesResponse {
+        required {
+            modules: Vec<Module>,
+        }
+        optional {
+            total_modules: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ModulesResponse {
+        required {
+            "modules": Vec<Module>,
+        }
+        optional {
+            "totalModules": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ModulesResponse {
+        required {
+            "modules": Vec<Module>,
+        }
+        optional {
+            "totalModules": u64,
+        }
+    }
 }
 
 /// Arguments for `loadedSources` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
-pub struct LoadedSourcesArguments {}
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct LoadedSourcesArguments {
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    LoadedSourcesArguments {
+        required {}
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    LoadedSourcesArguments {
+        required {}
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    LoadedSourcesArguments {
+        required {}
+        optional {}
+    }
+}
 
 /// Response to `loadedSources` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct LoadedSourcesResponse {
     /// Set of loaded sources.
     #[serde(rename = "sources")]
     pub sources: Vec<Source>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    LoadedSourcesResponse {
+        required {
+            sources: Vec<Source>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    LoadedSourcesResponse {
+        required {
+            "sources": Vec<Source>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    LoadedSourcesResponse {
+        required {
+            "sources": Vec<Source>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `evaluate` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct EvaluateArguments {
     /// The expression to evaluate.
     #[serde(rename = "expression")]
@@ -1317,7 +3873,7 @@ pub struct EvaluateArguments {
     #[serde(rename = "frameId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub frame_id: Option<u64>,
+    pub frame_id: Option<i64>,
     /// The contextual line where the expression should be evaluated. In the 'hover' context, this should be set to the start of the expression being hovered.
     #[serde(rename = "line")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1346,35 +3902,94 @@ pub struct EvaluateArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub format: Option<ValueFormat>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    EvaluateArguments {
+        required {
+            expression: String,
+        }
+        optional {
+            frame_id: i64,
+            line: u64,
+            column: u64,
+            source: Source,
+            context: EvaluateArgumentsContext,
+            format: ValueFormat,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    EvaluateArguments {
+        required {
+            "expression": String,
+        }
+        optional {
+            "frameId": u64,
+            "line": u64,
+            "column": u64,
+            "source": Source,
+            "context": EvaluateArgumentsContext,
+            "format": ValueFormat,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    EvaluateArguments {
+        required {
+            "expression": String,
+        }
+        optional {
+            "frameId": u64,
+            "line": u64,
+            "column": u64,
+            "source": Source,
+            "context": EvaluateArgumentsContext,
+            "format": ValueFormat,
+        }
+    }
 }
 
 /// The context in which the evaluate request is used.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum EvaluateArgumentsContext {
     /// evaluate is called from a watch view context.
-    #[serde(rename = "watch")]
     Watch,
     /// evaluate is called from a REPL context.
-    #[serde(rename = "repl")]
     Repl,
     /// evaluate is called to generate the debug hover contents.
     /// This value should only be used if the corresponding capability `supportsEvaluateForHovers` is true.
-    #[serde(rename = "hover")]
     Hover,
     /// evaluate is called to generate clipboard contents.
     /// This value should only be used if the corresponding capability `supportsClipboardContext` is true.
-    #[serde(rename = "clipboard")]
     Clipboard,
     /// evaluate is called from a variables view context.
-    #[serde(rename = "variables")]
     Variables,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// The context in which the evaluate request is used.
+    EvaluateArgumentsContext {
+        Watch: "watch",
+        Repl: "repl",
+        Hover: "hover",
+        Clipboard: "clipboard",
+        Variables: "variables",
+    }
 }
 
 /// Response to `evaluate` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct EvaluateResponse {
     /// The result of the evaluate request.
     #[serde(rename = "result")]
@@ -1392,7 +4007,7 @@ pub struct EvaluateResponse {
     pub presentation_hint: Option<VariablePresentationHint>,
     /// If `variablesReference` is > 0, the evaluate result is structured and its children can be retrieved by passing `variablesReference` to the `variables` request as long as execution remains suspended. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
-    pub variables_reference: u64,
+    pub variables_reference: i64,
     /// The number of named child variables.
     /// The client can use this information to present the variables in a paged UI and fetch them in chunks.
     /// The value should be less than or equal to 2147483647 (2^31-1).
@@ -1420,11 +4035,67 @@ pub struct EvaluateResponse {
     #[serde(rename = "valueLocationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub value_location_reference: Option<u64>,
+    pub value_location_reference: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    EvaluateResponse {
+        required {
+            result: String,
+            variables_reference: i64,
+        }
+        optional {
+            type_: String,
+            presentation_hint: VariablePresentationHint,
+            named_variables: u64,
+            indexed_variables: u64,
+            memory_reference: String,
+            value_location_reference: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    EvaluateResponse {
+        required {
+            "result": String,
+            "variablesReference": u64,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    EvaluateResponse {
+        required {
+            "result": String,
+            "variablesReference": u64,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
 }
 
 /// Arguments for `setExpression` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetExpressionArguments {
     /// The l-value expression to assign to.
     #[serde(rename = "expression")]
@@ -1436,16 +4107,60 @@ pub struct SetExpressionArguments {
     #[serde(rename = "frameId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub frame_id: Option<u64>,
+    pub frame_id: Option<i64>,
     /// Specifies how the resulting value should be formatted.
     #[serde(rename = "format")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub format: Option<ValueFormat>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetExpressionArguments {
+        required {
+            expression: String,
+            value: String,
+        }
+        optional {
+            frame_id: i64,
+            format: ValueFormat,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetExpressionArguments {
+        required {
+            "expression": String,
+            "value": String,
+        }
+        optional {
+            "frameId": u64,
+            "format": ValueFormat,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetExpressionArguments {
+        required {
+            "expression": String,
+            "value": String,
+        }
+        optional {
+            "frameId": u64,
+            "format": ValueFormat,
+        }
+    }
 }
 
 /// Response to `setExpression` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SetExpressionResponse {
     /// The new value of the expression.
     #[serde(rename = "value")]
@@ -1465,7 +4180,7 @@ pub struct SetExpressionResponse {
     #[serde(rename = "variablesReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub variables_reference: Option<u64>,
+    pub variables_reference: Option<i64>,
     /// The number of named child variables.
     /// The client can use this information to present the variables in a paged UI and fetch them in chunks.
     /// The value should be less than or equal to 2147483647 (2^31-1).
@@ -1493,27 +4208,147 @@ pub struct SetExpressionResponse {
     #[serde(rename = "valueLocationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub value_location_reference: Option<u64>,
+    pub value_location_reference: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SetExpressionResponse {
+        required {
+            value: String,
+        }
+        optional {
+            type_: String,
+            presentation_hint: VariablePresentationHint,
+            variables_reference: i64,
+            named_variables: u64,
+            indexed_variables: u64,
+            memory_reference: String,
+            value_location_reference: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SetExpressionResponse {
+        required {
+            "value": String,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "variablesReference": u64,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SetExpressionResponse {
+        required {
+            "value": String,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "variablesReference": u64,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "valueLocationReference": u64,
+        }
+    }
 }
 
 /// Arguments for `stepInTargets` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepInTargetsArguments {
     /// The stack frame for which to retrieve the possible step-in targets.
     #[serde(rename = "frameId")]
-    pub frame_id: u64,
+    pub frame_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepInTargetsArguments {
+        required {
+            frame_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    StepInTargetsArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepInTargetsArguments {
+        required {
+            "frameId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Response to `stepInTargets` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepInTargetsResponse {
     /// The possible step-in targets of the specified source location.
     #[serde(rename = "targets")]
     pub targets: Vec<StepInTarget>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepInTargetsResponse {
+        required {
+            targets: Vec<StepInTarget>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    StepInTargetsResponse {
+        required {
+            "targets": Vec<StepInTarget>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepInTargetsResponse {
+        required {
+            "targets": Vec<StepInTarget>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `gotoTargets` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct GotoTargetsArguments {
     /// The source location for which the goto targets are determined.
     #[serde(rename = "source")]
@@ -1526,24 +4361,97 @@ pub struct GotoTargetsArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    GotoTargetsArguments {
+        required {
+            source: Source,
+            line: u64,
+        }
+        optional {
+            column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    GotoTargetsArguments {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    GotoTargetsArguments {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+        }
+    }
 }
 
 /// Response to `gotoTargets` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct GotoTargetsResponse {
     /// The possible goto targets of the specified location.
     #[serde(rename = "targets")]
     pub targets: Vec<GotoTarget>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    GotoTargetsResponse {
+        required {
+            targets: Vec<GotoTarget>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    GotoTargetsResponse {
+        required {
+            "targets": Vec<GotoTarget>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    GotoTargetsResponse {
+        required {
+            "targets": Vec<GotoTarget>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `completions` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct CompletionsArguments {
     /// Returns completions in the scope of this stack frame. If not specified, the completions are returned for the global scope.
     #[serde(rename = "frameId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub frame_id: Option<u64>,
+    pub frame_id: Option<i64>,
     /// One or more source lines. Typically this is the text users have typed into the debug console before they asked for completion.
     #[serde(rename = "text")]
     pub text: String,
@@ -1555,26 +4463,134 @@ pub struct CompletionsArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub line: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    CompletionsArguments {
+        required {
+            text: String,
+            column: u64,
+        }
+        optional {
+            frame_id: i64,
+            line: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    CompletionsArguments {
+        required {
+            "text": String,
+            "column": u64,
+        }
+        optional {
+            "frameId": u64,
+            "line": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    CompletionsArguments {
+        required {
+            "text": String,
+            "column": u64,
+        }
+        optional {
+            "frameId": u64,
+            "line": u64,
+        }
+    }
 }
 
 /// Response to `completions` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct CompletionsResponse {
     /// The possible completions for .
     #[serde(rename = "targets")]
     pub targets: Vec<CompletionItem>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    CompletionsResponse {
+        required {
+            targets: Vec<CompletionItem>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    CompletionsResponse {
+        required {
+            "targets": Vec<CompletionItem>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    CompletionsResponse {
+        required {
+            "targets": Vec<CompletionItem>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `exceptionInfo` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionInfoArguments {
     /// Thread for which exception information should be retrieved.
     #[serde(rename = "threadId")]
     pub thread_id: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionInfoArguments {
+        required {
+            thread_id: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionInfoArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionInfoArguments {
+        required {
+            "threadId": u64,
+        }
+        optional {}
+    }
 }
 
 /// Response to `exceptionInfo` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionInfoResponse {
     /// ID of the exception that was thrown.
     #[serde(rename = "exceptionId")]
@@ -1592,10 +4608,54 @@ pub struct ExceptionInfoResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub details: Option<ExceptionDetails>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionInfoResponse {
+        required {
+            exception_id: String,
+            break_mode: ExceptionBreakMode,
+        }
+        optional {
+            description: String,
+            details: ExceptionDetails,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionInfoResponse {
+        required {
+            "exceptionId": String,
+            "breakMode": ExceptionBreakMode,
+        }
+        optional {
+            "description": String,
+            "details": ExceptionDetails,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionInfoResponse {
+        required {
+            "exceptionId": String,
+            "breakMode": ExceptionBreakMode,
+        }
+        optional {
+            "description": String,
+            "details": ExceptionDetails,
+        }
+    }
 }
 
 /// Arguments for `readMemory` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ReadMemoryArguments {
     /// Memory reference to the base location from which data should be read.
     #[serde(rename = "memoryReference")]
@@ -1604,14 +4664,55 @@ pub struct ReadMemoryArguments {
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// Number of bytes to read at the specified location and offset.
     #[serde(rename = "count")]
     pub count: u64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ReadMemoryArguments {
+        required {
+            memory_reference: String,
+            count: u64,
+        }
+        optional {
+            offset: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ReadMemoryArguments {
+        required {
+            "memoryReference": String,
+            "count": u64,
+        }
+        optional {
+            "offset": i64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ReadMemoryArguments {
+        required {
+            "memoryReference": String,
+            "count": u64,
+        }
+        optional {
+            "offset": i64,
+        }
+    }
 }
 
 /// Response to `readMemory` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ReadMemoryResponse {
     /// The address of the first byte of data returned.
     /// Treated as a hex value if prefixed with `0x`, or as a decimal value otherwise.
@@ -1628,10 +4729,51 @@ pub struct ReadMemoryResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub data: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ReadMemoryResponse {
+        required {
+            address: String,
+        }
+        optional {
+            unreadable_bytes: u64,
+            data: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ReadMemoryResponse {
+        required {
+            "address": String,
+        }
+        optional {
+            "unreadableBytes": u64,
+            "data": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ReadMemoryResponse {
+        required {
+            "address": String,
+        }
+        optional {
+            "unreadableBytes": u64,
+            "data": String,
+        }
+    }
 }
 
 /// Arguments for `writeMemory` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct WriteMemoryArguments {
     /// Memory reference to the base location to which data should be written.
     #[serde(rename = "memoryReference")]
@@ -1640,7 +4782,7 @@ pub struct WriteMemoryArguments {
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// Property to control partial writes. If true, the debug adapter should attempt to write memory even if the entire memory region is not writable. In such a case the debug adapter should stop after hitting the first byte of memory that cannot be written and return the number of bytes written in the response via the `offset` and `bytesWritten` properties.
     /// If false or missing, a debug adapter should attempt to verify the region is writable before writing, and fail the response if it is not.
     #[serde(rename = "allowPartial")]
@@ -1650,25 +4792,104 @@ pub struct WriteMemoryArguments {
     /// Bytes to write, encoded using base64.
     #[serde(rename = "data")]
     pub data: String,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    WriteMemoryArguments {
+        required {
+            memory_reference: String,
+            data: String,
+        }
+        optional {
+            offset: i64,
+            allow_partial: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    WriteMemoryArguments {
+        required {
+            "memoryReference": String,
+            "data": String,
+        }
+        optional {
+            "offset": i64,
+            "allowPartial": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    WriteMemoryArguments {
+        required {
+            "memoryReference": String,
+            "data": String,
+        }
+        optional {
+            "offset": i64,
+            "allowPartial": bool,
+        }
+    }
 }
 
 /// Response to `writeMemory` request.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct WriteMemoryResponse {
     /// Property that should be returned when `allowPartial` is true to indicate the offset of the first byte of data successfully written. Can be negative.
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// Property that should be returned when `allowPartial` is true to indicate the number of bytes starting from address that were successfully written.
     #[serde(rename = "bytesWritten")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub bytes_written: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    WriteMemoryResponse {
+        required {}
+        optional {
+            offset: i64,
+            bytes_written: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    WriteMemoryResponse {
+        required {}
+        optional {
+            "offset": i64,
+            "bytesWritten": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    WriteMemoryResponse {
+        required {}
+        optional {
+            "offset": i64,
+            "bytesWritten": u64,
+        }
+    }
 }
 
 /// Arguments for `disassemble` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DisassembleArguments {
     /// Memory reference to the base location containing the instructions to disassemble.
     #[serde(rename = "memoryReference")]
@@ -1677,12 +4898,12 @@ pub struct DisassembleArguments {
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// Offset (in instructions) to be applied after the byte offset (if any) before disassembling. Can be negative.
     #[serde(rename = "instructionOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub instruction_offset: Option<u64>,
+    pub instruction_offset: Option<i64>,
     /// Number of instructions to disassemble starting at the specified location and offset.
     /// An adapter must return exactly this number of instructions - any unavailable instructions should be replaced with an implementation-defined 'invalid instruction' value.
     #[serde(rename = "instructionCount")]
@@ -1692,26 +4913,137 @@ pub struct DisassembleArguments {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub resolve_symbols: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DisassembleArguments {
+        required {
+            memory_reference: String,
+            instruction_count: u64,
+        }
+        optional {
+            offset: i64,
+            instruction_offset: i64,
+            resolve_symbols: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DisassembleArguments {
+        required {
+            "memoryReference": String,
+            "instructionCount": u64,
+        }
+        optional {
+            "offset": i64,
+            "instructionOffset": i64,
+            "resolveSymbols": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DisassembleArguments {
+        required {
+            "memoryReference": String,
+            "instructionCount": u64,
+        }
+        optional {
+            "offset": i64,
+            "instructionOffset": i64,
+            "resolveSymbols": bool,
+        }
+    }
 }
 
 /// Response to `disassemble` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DisassembleResponse {
     /// The list of disassembled instructions.
     #[serde(rename = "instructions")]
     pub instructions: Vec<DisassembledInstruction>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DisassembleResponse {
+        required {
+            instructions: Vec<DisassembledInstruction>,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    DisassembleResponse {
+        required {
+            "instructions": Vec<DisassembledInstruction>,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DisassembleResponse {
+        required {
+            "instructions": Vec<DisassembledInstruction>,
+        }
+        optional {}
+    }
 }
 
 /// Arguments for `locations` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct LocationsArguments {
     /// Location reference to resolve.
     #[serde(rename = "locationReference")]
-    pub location_reference: u64,
+    pub location_reference: i64,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    LocationsArguments {
+        required {
+            location_reference: i64,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    LocationsArguments {
+        required {
+            "locationReference": u64,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    LocationsArguments {
+        required {
+            "locationReference": u64,
+        }
+        optional {}
+    }
 }
 
 /// Response to `locations` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct LocationsResponse {
     /// The source containing the location; either `source.path` or `source.sourceReference` must be specified.
     #[serde(rename = "source")]
@@ -1734,10 +5066,57 @@ pub struct LocationsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub end_column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    LocationsResponse {
+        required {
+            source: Source,
+            line: u64,
+        }
+        optional {
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    LocationsResponse {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    LocationsResponse {
+        required {
+            "source": Source,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
 }
 
 /// Information about the capabilities of a debug adapter.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Capabilities {
     /// The debug adapter supports the `configurationDone` request.
     #[serde(rename = "supportsConfigurationDoneRequest")]
@@ -1951,10 +5330,212 @@ pub struct Capabilities {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub supports_ansistyling: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::merge::impl_merge! {
+    Capabilities {
+        supports_configuration_done_request: "supportsConfigurationDoneRequest",
+        supports_function_breakpoints: "supportsFunctionBreakpoints",
+        supports_conditional_breakpoints: "supportsConditionalBreakpoints",
+        supports_hit_conditional_breakpoints: "supportsHitConditionalBreakpoints",
+        supports_evaluate_for_hovers: "supportsEvaluateForHovers",
+        exception_breakpoint_filters: "exceptionBreakpointFilters",
+        supports_step_back: "supportsStepBack",
+        supports_set_variable: "supportsSetVariable",
+        supports_restart_frame: "supportsRestartFrame",
+        supports_goto_targets_request: "supportsGotoTargetsRequest",
+        supports_step_in_targets_request: "supportsStepInTargetsRequest",
+        supports_completions_request: "supportsCompletionsRequest",
+        completion_trigger_characters: "completionTriggerCharacters",
+        supports_modules_request: "supportsModulesRequest",
+        additional_module_columns: "additionalModuleColumns",
+        supported_checksum_algorithms: "supportedChecksumAlgorithms",
+        supports_restart_request: "supportsRestartRequest",
+        supports_exception_options: "supportsExceptionOptions",
+        supports_value_formatting_options: "supportsValueFormattingOptions",
+        supports_exception_info_request: "supportsExceptionInfoRequest",
+        support_terminate_debuggee: "supportTerminateDebuggee",
+        support_suspend_debuggee: "supportSuspendDebuggee",
+        supports_delayed_stack_trace_loading: "supportsDelayedStackTraceLoading",
+        supports_loaded_sources_request: "supportsLoadedSourcesRequest",
+        supports_log_points: "supportsLogPoints",
+        supports_terminate_threads_request: "supportsTerminateThreadsRequest",
+        supports_set_expression: "supportsSetExpression",
+        supports_terminate_request: "supportsTerminateRequest",
+        supports_data_breakpoints: "supportsDataBreakpoints",
+        supports_read_memory_request: "supportsReadMemoryRequest",
+        supports_write_memory_request: "supportsWriteMemoryRequest",
+        supports_disassemble_request: "supportsDisassembleRequest",
+        supports_cancel_request: "supportsCancelRequest",
+        supports_breakpoint_locations_request: "supportsBreakpointLocationsRequest",
+        supports_clipboard_context: "supportsClipboardContext",
+        supports_stepping_granularity: "supportsSteppingGranularity",
+        supports_instruction_breakpoints: "supportsInstructionBreakpoints",
+        supports_exception_filter_options: "supportsExceptionFilterOptions",
+        supports_single_thread_execution_requests: "supportsSingleThreadExecutionRequests",
+        supports_data_breakpoint_bytes: "supportsDataBreakpointBytes",
+        breakpoint_modes: "breakpointModes",
+        supports_ansistyling: "supportsANSIStyling",
+    }
+}
+
+crate::builder::impl_builder! {
+    Capabilities {
+        required {}
+        optional {
+            supports_configuration_done_request: bool,
+            supports_function_breakpoints: bool,
+            supports_conditional_breakpoints: bool,
+            supports_hit_conditional_breakpoints: bool,
+            supports_evaluate_for_hovers: bool,
+            exception_breakpoint_filters: Vec<ExceptionBreakpointsFilter>,
+            supports_step_back: bool,
+            supports_set_variable: bool,
+            supports_restart_frame: bool,
+            supports_goto_targets_request: bool,
+            supports_step_in_targets_request: bool,
+            supports_completions_request: bool,
+            completion_trigger_characters: Vec<String>,
+            supports_modules_request: bool,
+            additional_module_columns: Vec<ColumnDescriptor>,
+            supported_checksum_algorithms: Vec<ChecksumAlgorithm>,
+            supports_restart_request: bool,
+            supports_exception_options: bool,
+            supports_value_formatting_options: bool,
+            supports_exception_info_request: bool,
+            support_terminate_debuggee: bool,
+            support_suspend_debuggee: bool,
+            supports_delayed_stack_trace_loading: bool,
+            supports_loaded_sources_request: bool,
+            supports_log_points: bool,
+            supports_terminate_threads_request: bool,
+            supports_set_expression: bool,
+            supports_terminate_request: bool,
+            supports_data_breakpoints: bool,
+            supports_read_memory_request: bool,
+            supports_write_memory_request: bool,
+            supports_disassemble_request: bool,
+            supports_cancel_request: bool,
+            supports_breakpoint_locations_request: bool,
+            supports_clipboard_context: bool,
+            supports_stepping_granularity: bool,
+            supports_instruction_breakpoints: bool,
+            supports_exception_filter_options: bool,
+            supports_single_thread_execution_requests: bool,
+            supports_data_breakpoint_bytes: bool,
+            breakpoint_modes: Vec<BreakpointMode>,
+            supports_ansistyling: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Capabilities {
+        required {}
+        optional {
+            "supportsConfigurationDoneRequest": bool,
+            "supportsFunctionBreakpoints": bool,
+            "supportsConditionalBreakpoints": bool,
+            "supportsHitConditionalBreakpoints": bool,
+            "supportsEvaluateForHovers": bool,
+            "exceptionBreakpointFilters": Vec<ExceptionBreakpointsFilter>,
+            "supportsStepBack": bool,
+            "supportsSetVariable": bool,
+            "supportsRestartFrame": bool,
+            "supportsGotoTargetsRequest": bool,
+            "supportsStepInTargetsRequest": bool,
+            "supportsCompletionsRequest": bool,
+            "completionTriggerCharacters": Vec<String>,
+            "supportsModulesRequest": bool,
+            "additionalModuleColumns": Vec<ColumnDescriptor>,
+            "supportedChecksumAlgorithms": Vec<ChecksumAlgorithm>,
+            "supportsRestartRequest": bool,
+            "supportsExceptionOptions": bool,
+            "supportsValueFormattingOptions": bool,
+            "supportsExceptionInfoRequest": bool,
+            "supportTerminateDebuggee": bool,
+            "supportSuspendDebuggee": bool,
+            "supportsDelayedStackTraceLoading": bool,
+            "supportsLoadedSourcesRequest": bool,
+            "supportsLogPoints": bool,
+            "supportsTerminateThreadsRequest": bool,
+            "supportsSetExpression": bool,
+            "supportsTerminateRequest": bool,
+            "supportsDataBreakpoints": bool,
+            "supportsReadMemoryRequest": bool,
+            "supportsWriteMemoryRequest": bool,
+            "supportsDisassembleRequest": bool,
+            "supportsCancelRequest": bool,
+            "supportsBreakpointLocationsRequest": bool,
+            "supportsClipboardContext": bool,
+            "supportsSteppingGranularity": bool,
+            "supportsInstructionBreakpoints": bool,
+            "supportsExceptionFilterOptions": bool,
+            "supportsSingleThreadExecutionRequests": bool,
+            "supportsDataBreakpointBytes": bool,
+            "breakpointModes": Vec<BreakpointMode>,
+            "supportsANSIStyling": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Capabilities {
+        required {}
+        optional {
+            "supportsConfigurationDoneRequest": bool,
+            "supportsFunctionBreakpoints": bool,
+            "supportsConditionalBreakpoints": bool,
+            "supportsHitConditionalBreakpoints": bool,
+            "supportsEvaluateForHovers": bool,
+            "exceptionBreakpointFilters": Vec<ExceptionBreakpointsFilter>,
+            "supportsStepBack": bool,
+            "supportsSetVariable": bool,
+            "supportsRestartFrame": bool,
+            "supportsGotoTargetsRequest": bool,
+            "supportsStepInTargetsRequest": bool,
+            "supportsCompletionsRequest": bool,
+            "completionTriggerCharacters": Vec<String>,
+            "supportsModulesRequest": bool,
+            "additionalModuleColumns": Vec<ColumnDescriptor>,
+            "supportedChecksumAlgorithms": Vec<ChecksumAlgorithm>,
+            "supportsRestartRequest": bool,
+            "supportsExceptionOptions": bool,
+            "supportsValueFormattingOptions": bool,
+            "supportsExceptionInfoRequest": bool,
+            "supportTerminateDebuggee": bool,
+            "supportSuspendDebuggee": bool,
+            "supportsDelayedStackTraceLoading": bool,
+            "supportsLoadedSourcesRequest": bool,
+            "supportsLogPoints": bool,
+            "supportsTerminateThreadsRequest": bool,
+            "supportsSetExpression": bool,
+            "supportsTerminateRequest": bool,
+            "supportsDataBreakpoints": bool,
+            "supportsReadMemoryRequest": bool,
+            "supportsWriteMemoryRequest": bool,
+            "supportsDisassembleRequest": bool,
+            "supportsCancelRequest": bool,
+            "supportsBreakpointLocationsRequest": bool,
+            "supportsClipboardContext": bool,
+            "supportsSteppingGranularity": bool,
+            "supportsInstructionBreakpoints": bool,
+            "supportsExceptionFilterOptions": bool,
+            "supportsSingleThreadExecutionRequests": bool,
+            "supportsDataBreakpointBytes": bool,
+            "breakpointModes": Vec<BreakpointMode>,
+            "supportsANSIStyling": bool,
+        }
+    }
 }
 
 /// An `ExceptionBreakpointsFilter` is shown in the UI as an filter option for configuring how exceptions are dealt with.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionBreakpointsFilter {
     /// The internal ID of the filter option. This value is passed to the `setExceptionBreakpoints` request.
     #[serde(rename = "filter")]
@@ -1982,14 +5563,64 @@ pub struct ExceptionBreakpointsFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub condition_description: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionBreakpointsFilter {
+        required {
+            filter: String,
+            label: String,
+        }
+        optional {
+            description: String,
+            default: bool,
+            supports_condition: bool,
+            condition_description: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionBreakpointsFilter {
+        required {
+            "filter": String,
+            "label": String,
+        }
+        optional {
+            "description": String,
+            "default": bool,
+            "supportsCondition": bool,
+            "conditionDescription": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionBreakpointsFilter {
+        required {
+            "filter": String,
+            "label": String,
+        }
+        optional {
+            "description": String,
+            "default": bool,
+            "supportsCondition": bool,
+            "conditionDescription": String,
+        }
+    }
 }
 
 /// A structured message object. Used to return errors from requests.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Message {
     /// Unique (within a debug adapter implementation) identifier for the message. The purpose of these error IDs is to help extension authors that have the requirement that every user visible error message needs a corresponding error number, so that users or customer support can find information about the specific error more easily.
     #[serde(rename = "id")]
-    pub id: u64,
+    pub id: i64,
     /// A format string for the message. Embedded variables have the form `{name}`.
     /// If variable name starts with an underscore character, the variable does not contain user data (PII) and can be safely used for telemetry purposes.
     #[serde(rename = "format")]
@@ -2019,6 +5650,58 @@ pub struct Message {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub url_label: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Message {
+        required {
+            id: i64,
+            format: String,
+        }
+        optional {
+            variables: serde_json::Value,
+            send_telemetry: bool,
+            show_user: bool,
+            url: String,
+            url_label: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Message {
+        required {
+            "id": u64,
+            "format": String,
+        }
+        optional {
+            "variables": serde_json::Value,
+            "sendTelemetry": bool,
+            "showUser": bool,
+            "url": String,
+            "urlLabel": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Message {
+        required {
+            "id": u64,
+            "format": String,
+        }
+        optional {
+            "variables": serde_json::Value,
+            "sendTelemetry": bool,
+            "showUser": bool,
+            "url": String,
+            "urlLabel": String,
+        }
+    }
 }
 
 /// A Module object represents a row in the modules view.
@@ -2029,6 +5712,7 @@ pub struct Message {
 ///
 /// To avoid an unnecessary proliferation of additional attributes with similar semantics but different names, we recommend to re-use attributes from the 'recommended' list below first, and only introduce new attributes if nothing appropriate could be found.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Module {
     /// Unique identifier for the module.
     #[serde(rename = "id")]
@@ -2076,12 +5760,74 @@ pub struct Module {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub address_range: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Module {
+        required {
+            id: ModuleId,
+            name: String,
+        }
+        optional {
+            path: String,
+            is_optimized: bool,
+            is_user_code: bool,
+            version: String,
+            symbol_status: String,
+            symbol_file_path: String,
+            date_time_stamp: String,
+            address_range: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Module {
+        required {
+            "id": ModuleId,
+            "name": String,
+        }
+        optional {
+            "path": String,
+            "isOptimized": bool,
+            "isUserCode": bool,
+            "version": String,
+            "symbolStatus": String,
+            "symbolFilePath": String,
+            "dateTimeStamp": String,
+            "addressRange": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Module {
+        required {
+            "id": ModuleId,
+            "name": String,
+        }
+        optional {
+            "path": String,
+            "isOptimized": bool,
+            "isUserCode": bool,
+            "version": String,
+            "symbolStatus": String,
+            "symbolFilePath": String,
+            "dateTimeStamp": String,
+            "addressRange": String,
+        }
+    }
 }
 
 /// A `ColumnDescriptor` specifies what module attribute to show in a column of the modules view, how to format it,
 /// and what the column's label should be.
 /// It is only used if the underlying UI actually supports this level of customization.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ColumnDescriptor {
     /// Name of the attribute rendered in this column.
     #[serde(rename = "attributeName")]
@@ -2104,10 +5850,57 @@ pub struct ColumnDescriptor {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub width: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ColumnDescriptor {
+        required {
+            attribute_name: String,
+            label: String,
+        }
+        optional {
+            format: String,
+            type_: ColumnDescriptorType,
+            width: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ColumnDescriptor {
+        required {
+            "attributeName": String,
+            "label": String,
+        }
+        optional {
+            "format": String,
+            "type": ColumnDescriptorType,
+            "width": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ColumnDescriptor {
+        required {
+            "attributeName": String,
+            "label": String,
+        }
+        optional {
+            "format": String,
+            "type": ColumnDescriptorType,
+            "width": u64,
+        }
+    }
 }
 
 /// Datatype of values in this column. Defaults to `string` if not specified.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ColumnDescriptorType {
     #[serde(rename = "string")]
     String,
@@ -2119,8 +5912,13 @@ pub enum ColumnDescriptorType {
     UnixTimestampUtc,
 }
 
+crate::strict::impl_strict! { ColumnDescriptorType }
+
+crate::lenient::impl_lenient! { ColumnDescriptorType }
+
 /// A Thread
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Thread {
     /// Unique identifier for the thread.
     #[serde(rename = "id")]
@@ -2129,11 +5927,46 @@ pub struct Thread {
     #[serde(rename = "name")]
     #[serde(default)]
     pub name: String,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Thread {
+        required {
+            id: i64,
+            name: String,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    Thread {
+        required {
+            "id": u64,
+            "name": String,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Thread {
+        required {
+            "id": u64,
+            "name": String,
+        }
+        optional {}
+    }
 }
 
 /// A `Source` is a descriptor for source code.
 /// It is returned from the debug adapter as part of a `StackFrame` and it is used by clients when specifying breakpoints.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Source {
     /// The short name of the source. Every source returned from the debug adapter has a name.
     /// When sending a source to the debug adapter this name is optional.
@@ -2153,7 +5986,7 @@ pub struct Source {
     #[serde(rename = "sourceReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub source_reference: Option<u64>,
+    pub source_reference: Option<i64>,
     /// A hint for how to present the source in the UI.
     /// A value of `deemphasize` can be used to indicate that the source is not available or that it is skipped on stepping.
     #[serde(rename = "presentationHint")]
@@ -2181,29 +6014,89 @@ pub struct Source {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub checksums: Option<Vec<Checksum>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Source {
+        required {}
+        optional {
+            name: String,
+            path: String,
+            source_reference: i64,
+            presentation_hint: SourcePresentationHint,
+            origin: String,
+            sources: Vec<Source>,
+            adapter_data: serde_json::Value,
+            checksums: Vec<Checksum>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Source {
+        required {}
+        optional {
+            "name": String,
+            "path": String,
+            "sourceReference": u64,
+            "presentationHint": SourcePresentationHint,
+            "origin": String,
+            "sources": Vec<Source>,
+            "adapterData": serde_json::Value,
+            "checksums": Vec<Checksum>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Source {
+        required {}
+        optional {
+            "name": String,
+            "path": String,
+            "sourceReference": u64,
+            "presentationHint": SourcePresentationHint,
+            "origin": String,
+            "sources": Vec<Source>,
+            "adapterData": serde_json::Value,
+            "checksums": Vec<Checksum>,
+        }
+    }
 }
 
 /// A hint for how to present the source in the UI.
 /// A value of `deemphasize` can be used to indicate that the source is not available or that it is skipped on stepping.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone)]
 pub enum SourcePresentationHint {
-    #[serde(rename = "normal")]
     Normal,
-    #[serde(rename = "emphasize")]
     Emphasize,
-    #[serde(rename = "deemphasize")]
     Deemphasize,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// A hint for how to present the source in the UI.
+    /// A value of `deemphasize` can be used to indicate that the source is not available or that it is skipped on stepping.
+    SourcePresentationHint {
+        Normal: "normal",
+        Emphasize: "emphasize",
+        Deemphasize: "deemphasize",
+    }
 }
 
 /// A Stackframe contains the source location.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StackFrame {
     /// An identifier for the stack frame. It must be unique across all threads.
     /// This id can be used to retrieve the scopes of the frame with the `scopes` request or to restart the execution of a stack frame.
     #[serde(rename = "id")]
-    pub id: u64,
+    pub id: i64,
     /// The name of the stack frame, typically a method name.
     #[serde(rename = "name")]
     pub name: String,
@@ -2249,26 +6142,100 @@ pub struct StackFrame {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub presentation_hint: Option<StackFramePresentationHint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StackFrame {
+        required {
+            id: i64,
+            name: String,
+            line: u64,
+            column: u64,
+        }
+        optional {
+            source: Source,
+            end_line: u64,
+            end_column: u64,
+            can_restart: bool,
+            instruction_pointer_reference: String,
+            module_id: ModuleId,
+            presentation_hint: StackFramePresentationHint,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StackFrame {
+        required {
+            "id": u64,
+            "name": String,
+            "line": u64,
+            "column": u64,
+        }
+        optional {
+            "source": Source,
+            "endLine": u64,
+            "endColumn": u64,
+            "canRestart": bool,
+            "instructionPointerReference": String,
+            "moduleId": ModuleId,
+            "presentationHint": StackFramePresentationHint,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StackFrame {
+        required {
+            "id": u64,
+            "name": String,
+            "line": u64,
+            "column": u64,
+        }
+        optional {
+            "source": Source,
+            "endLine": u64,
+            "endColumn": u64,
+            "canRestart": bool,
+            "instructionPointerReference": String,
+            "moduleId": ModuleId,
+            "presentationHint": StackFramePresentationHint,
+        }
+    }
 }
 
 /// A hint for how to present this frame in the UI.
 /// A value of `label` can be used to indicate that the frame is an artificial frame that is used as a visual label or separator. A value of `subtle` can be used to change the appearance of a frame in a 'subtle' way.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone)]
 pub enum StackFramePresentationHint {
-    #[serde(rename = "normal")]
     Normal,
-    #[serde(rename = "label")]
     Label,
-    #[serde(rename = "subtle")]
     Subtle,
-    #[serde(rename = "deemphasize")]
     Deemphasize,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// A hint for how to present this frame in the UI.
+    /// A value of `label` can be used to indicate that the frame is an artificial frame that is used as a visual label or separator. A value of `subtle` can be used to change the appearance of a frame in a 'subtle' way.
+    StackFramePresentationHint {
+        Normal: "normal",
+        Label: "label",
+        Subtle: "subtle",
+    }
+    not_in_protocol {
+        Deemphasize: "deemphasize",
+    }
 }
 
 /// A `Scope` is a named container for variables. Optionally a scope can map to a source or a range within a source.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Scope {
     /// Name of the scope such as 'Arguments', 'Locals', or 'Registers'. This string is shown in the UI as is and can be translated.
     #[serde(rename = "name")]
@@ -2280,7 +6247,7 @@ pub struct Scope {
     pub presentation_hint: Option<ScopePresentationHint>,
     /// The variables of this scope can be retrieved by passing the value of `variablesReference` to the `variables` request as long as execution remains suspended. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
-    pub variables_reference: u64,
+    pub variables_reference: i64,
     /// The number of named variables in this scope.
     /// The client can use this information to present the variables in a paged UI and fetch them in chunks.
     #[serde(rename = "namedVariables")]
@@ -2321,26 +6288,96 @@ pub struct Scope {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub end_column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Scope {
+        required {
+            name: String,
+            variables_reference: i64,
+            expensive: bool,
+        }
+        optional {
+            presentation_hint: ScopePresentationHint,
+            named_variables: u64,
+            indexed_variables: u64,
+            source: Source,
+            line: u64,
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Scope {
+        required {
+            "name": String,
+            "variablesReference": u64,
+            "expensive": bool,
+        }
+        optional {
+            "presentationHint": ScopePresentationHint,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Scope {
+        required {
+            "name": String,
+            "variablesReference": u64,
+            "expensive": bool,
+        }
+        optional {
+            "presentationHint": ScopePresentationHint,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
 }
 
 /// A hint for how to present this scope in the UI. If this attribute is missing, the scope is shown with a generic UI.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum ScopePresentationHint {
     /// Scope contains method arguments.
-    #[serde(rename = "arguments")]
     Arguments,
     /// Scope contains local variables.
-    #[serde(rename = "locals")]
     Locals,
     /// Scope contains registers. Only a single `registers` scope should be returned from a `scopes` request.
-    #[serde(rename = "registers")]
     Registers,
     /// Scope contains one or more return values.
-    #[serde(rename = "returnValue")]
     ReturnValue,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// A hint for how to present this scope in the UI. If this attribute is missing, the scope is shown with a generic UI.
+    ScopePresentationHint {
+        Arguments: "arguments",
+        Locals: "locals",
+        Registers: "registers",
+        ReturnValue: "returnValue",
+    }
 }
 
 /// A Variable is a name/value pair.
@@ -2350,6 +6387,7 @@ pub enum ScopePresentationHint {
 /// If the number of named or indexed children is large, the numbers should be returned via the `namedVariables` and `indexedVariables` attributes.
 /// The client can use this information to present the children in a paged UI and fetch them in chunks.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Variable {
     /// The variable's name.
     #[serde(rename = "name")]
@@ -2378,7 +6416,7 @@ pub struct Variable {
     pub evaluate_name: Option<String>,
     /// If `variablesReference` is > 0, the variable is structured and its children can be retrieved by passing `variablesReference` to the `variables` request as long as execution remains suspended. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "variablesReference")]
-    pub variables_reference: u64,
+    pub variables_reference: i64,
     /// The number of named child variables.
     /// The client can use this information to present the children in a paged UI and fetch them in chunks.
     #[serde(rename = "namedVariables")]
@@ -2405,18 +6443,83 @@ pub struct Variable {
     #[serde(rename = "declarationLocationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub declaration_location_reference: Option<u64>,
+    pub declaration_location_reference: Option<i64>,
     /// A reference that allows the client to request the location where the variable's value is declared. For example, if the variable contains a function pointer, the adapter may be able to look up the function's location. This should be present only if the adapter is likely to be able to resolve the location.
     ///
     /// This reference shares the same lifetime as the `variablesReference`. See 'Lifetime of Object References' in the Overview section for details.
     #[serde(rename = "valueLocationReference")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub value_location_reference: Option<u64>,
+    pub value_location_reference: Option<i64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Variable {
+        required {
+            name: String,
+            value: String,
+            variables_reference: i64,
+        }
+        optional {
+            type_: String,
+            presentation_hint: VariablePresentationHint,
+            evaluate_name: String,
+            named_variables: u64,
+            indexed_variables: u64,
+            memory_reference: String,
+            declaration_location_reference: i64,
+            value_location_reference: i64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Variable {
+        required {
+            "name": String,
+            "value": String,
+            "variablesReference": u64,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "evaluateName": String,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "declarationLocationReference": u64,
+            "valueLocationReference": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Variable {
+        required {
+            "name": String,
+            "value": String,
+            "variablesReference": u64,
+        }
+        optional {
+            "type": String,
+            "presentationHint": VariablePresentationHint,
+            "evaluateName": String,
+            "namedVariables": u64,
+            "indexedVariables": u64,
+            "memoryReference": String,
+            "declarationLocationReference": u64,
+            "valueLocationReference": u64,
+        }
+    }
 }
 
 /// Properties of a variable that can be used to determine how to render the variable in the UI.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct VariablePresentationHint {
     /// The kind of variable. Before introducing additional values, try to use the listed values.
     #[serde(rename = "kind")]
@@ -2440,100 +6543,158 @@ pub struct VariablePresentationHint {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub lazy: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    VariablePresentationHint {
+        required {}
+        optional {
+            kind: VariablePresentationHintKind,
+            attributes: Vec<VariablePresentationHintAttributes>,
+            visibility: VariablePresentationHintVisibility,
+            lazy: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    VariablePresentationHint {
+        required {}
+        optional {
+            "kind": VariablePresentationHintKind,
+            "attributes": Vec<VariablePresentationHintAttributes>,
+            "visibility": VariablePresentationHintVisibility,
+            "lazy": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    VariablePresentationHint {
+        required {}
+        optional {
+            "kind": VariablePresentationHintKind,
+            "attributes": Vec<VariablePresentationHintAttributes>,
+            "visibility": VariablePresentationHintVisibility,
+            "lazy": bool,
+        }
+    }
 }
 
 /// The kind of variable. Before introducing additional values, try to use the listed values.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum VariablePresentationHintKind {
     /// Indicates that the object is a property.
-    #[serde(rename = "property")]
     Property,
     /// Indicates that the object is a method.
-    #[serde(rename = "method")]
     Method,
     /// Indicates that the object is a class.
-    #[serde(rename = "class")]
     Class,
     /// Indicates that the object is data.
-    #[serde(rename = "data")]
     Data,
     /// Indicates that the object is an event.
-    #[serde(rename = "event")]
     Event,
     /// Indicates that the object is a base class.
-    #[serde(rename = "baseClass")]
     BaseClass,
     /// Indicates that the object is an inner class.
-    #[serde(rename = "innerClass")]
     InnerClass,
     /// Indicates that the object is an interface.
-    #[serde(rename = "interface")]
     Interface,
     /// Indicates that the object is the most derived class.
-    #[serde(rename = "mostDerivedClass")]
     MostDerivedClass,
     /// Indicates that the object is virtual, that means it is a synthetic object introduced by the adapter for rendering purposes, e.g. an index range for large arrays.
-    #[serde(rename = "virtual")]
     Virtual,
     /// Deprecated: Indicates that a data breakpoint is registered for the object. The `hasDataBreakpoint` attribute should generally be used instead.
-    #[serde(rename = "dataBreakpoint")]
     DataBreakpoint,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
 }
 
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+crate::string_enum::impl_string_enum! {
+    /// The kind of variable. Before introducing additional values, try to use the listed values.
+    VariablePresentationHintKind {
+        Property: "property",
+        Method: "method",
+        Class: "class",
+        Data: "data",
+        Event: "event",
+        BaseClass: "baseClass",
+        InnerClass: "innerClass",
+        Interface: "interface",
+        MostDerivedClass: "mostDerivedClass",
+        Virtual: "virtual",
+        DataBreakpoint: "dataBreakpoint",
+    }
+}
+
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum VariablePresentationHintAttributes {
     /// Indicates that the object is static.
-    #[serde(rename = "static")]
     Static,
     /// Indicates that the object is a constant.
-    #[serde(rename = "constant")]
     Constant,
     /// Indicates that the object is read only.
-    #[serde(rename = "readOnly")]
     ReadOnly,
     /// Indicates that the object is a raw string.
-    #[serde(rename = "rawString")]
     RawString,
     /// Indicates that the object can have an Object ID created for it. This is a vestigial attribute that is used by some clients; 'Object ID's are not specified in the protocol.
-    #[serde(rename = "hasObjectId")]
     HasObjectId,
     /// Indicates that the object has an Object ID associated with it. This is a vestigial attribute that is used by some clients; 'Object ID's are not specified in the protocol.
-    #[serde(rename = "canHaveObjectId")]
     CanHaveObjectId,
     /// Indicates that the evaluation had side effects.
-    #[serde(rename = "hasSideEffects")]
     HasSideEffects,
     /// Indicates that the object has its value tracked by a data breakpoint.
-    #[serde(rename = "hasDataBreakpoint")]
     HasDataBreakpoint,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    VariablePresentationHintAttributes {
+        Static: "static",
+        Constant: "constant",
+        ReadOnly: "readOnly",
+        RawString: "rawString",
+        HasObjectId: "hasObjectId",
+        CanHaveObjectId: "canHaveObjectId",
+        HasSideEffects: "hasSideEffects",
+        HasDataBreakpoint: "hasDataBreakpoint",
+    }
 }
 
 /// Visibility of variable. Before introducing additional values, try to use the listed values.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum VariablePresentationHintVisibility {
-    #[serde(rename = "public")]
     Public,
-    #[serde(rename = "private")]
     Private,
-    #[serde(rename = "protected")]
     Protected,
-    #[serde(rename = "internal")]
     Internal,
-    #[serde(rename = "final")]
     Final,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// Visibility of variable. Before introducing additional values, try to use the listed values.
+    VariablePresentationHintVisibility {
+        Public: "public",
+        Private: "private",
+        Protected: "protected",
+        Internal: "internal",
+        Final: "final",
+    }
 }
 
 /// Properties of a breakpoint location returned from the `breakpointLocations` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BreakpointLocation {
     /// Start line of breakpoint location.
     #[serde(rename = "line")]
@@ -2553,10 +6714,54 @@ pub struct BreakpointLocation {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub end_column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    BreakpointLocation {
+        required {
+            line: u64,
+        }
+        optional {
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    BreakpointLocation {
+        required {
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    BreakpointLocation {
+        required {
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
 }
 
 /// Properties of a breakpoint or logpoint passed to the `setBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct SourceBreakpoint {
     /// The source line of the breakpoint or logpoint.
     #[serde(rename = "line")]
@@ -2593,10 +6798,60 @@ pub struct SourceBreakpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub mode: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    SourceBreakpoint {
+        required {
+            line: u64,
+        }
+        optional {
+            column: u64,
+            condition: String,
+            hit_condition: String,
+            log_message: String,
+            mode: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    SourceBreakpoint {
+        required {
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "condition": String,
+            "hitCondition": String,
+            "logMessage": String,
+            "mode": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    SourceBreakpoint {
+        required {
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "condition": String,
+            "hitCondition": String,
+            "logMessage": String,
+            "mode": String,
+        }
+    }
 }
 
 /// Properties of a breakpoint passed to the `setFunctionBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct FunctionBreakpoint {
     /// The name of the function.
     #[serde(rename = "name")]
@@ -2614,10 +6869,51 @@ pub struct FunctionBreakpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub hit_condition: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    FunctionBreakpoint {
+        required {
+            name: String,
+        }
+        optional {
+            condition: String,
+            hit_condition: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    FunctionBreakpoint {
+        required {
+            "name": String,
+        }
+        optional {
+            "condition": String,
+            "hitCondition": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    FunctionBreakpoint {
+        required {
+            "name": String,
+        }
+        optional {
+            "condition": String,
+            "hitCondition": String,
+        }
+    }
 }
 
 /// This enumeration defines all possible access types for data breakpoints.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum DataBreakpointAccessType {
     #[serde(rename = "read")]
     Read,
@@ -2627,8 +6923,13 @@ pub enum DataBreakpointAccessType {
     ReadWrite,
 }
 
+crate::strict::impl_strict! { DataBreakpointAccessType }
+
+crate::lenient::impl_lenient! { DataBreakpointAccessType }
+
 /// Properties of a data breakpoint passed to the `setDataBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DataBreakpoint {
     /// An id representing the data. This id is returned from the `dataBreakpointInfo` request.
     #[serde(rename = "dataId")]
@@ -2649,10 +6950,54 @@ pub struct DataBreakpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub hit_condition: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DataBreakpoint {
+        required {
+            data_id: String,
+        }
+        optional {
+            access_type: DataBreakpointAccessType,
+            condition: String,
+            hit_condition: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DataBreakpoint {
+        required {
+            "dataId": String,
+        }
+        optional {
+            "accessType": DataBreakpointAccessType,
+            "condition": String,
+            "hitCondition": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DataBreakpoint {
+        required {
+            "dataId": String,
+        }
+        optional {
+            "accessType": DataBreakpointAccessType,
+            "condition": String,
+            "hitCondition": String,
+        }
+    }
 }
 
 /// Properties of a breakpoint passed to the `setInstructionBreakpoints` request
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct InstructionBreakpoint {
     /// The instruction reference of the breakpoint.
     /// This should be a memory or instruction pointer reference from an `EvaluateResponse`, `Variable`, `StackFrame`, `GotoTarget`, or `Breakpoint`.
@@ -2663,7 +7008,7 @@ pub struct InstructionBreakpoint {
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// An expression for conditional breakpoints.
     /// It is only honored by a debug adapter if the corresponding capability `supportsConditionalBreakpoints` is true.
     #[serde(rename = "condition")]
@@ -2682,16 +7027,63 @@ pub struct InstructionBreakpoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub mode: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    InstructionBreakpoint {
+        required {
+            instruction_reference: String,
+        }
+        optional {
+            offset: i64,
+            condition: String,
+            hit_condition: String,
+            mode: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    InstructionBreakpoint {
+        required {
+            "instructionReference": String,
+        }
+        optional {
+            "offset": i64,
+            "condition": String,
+            "hitCondition": String,
+            "mode": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    InstructionBreakpoint {
+        required {
+            "instructionReference": String,
+        }
+        optional {
+            "offset": i64,
+            "condition": String,
+            "hitCondition": String,
+            "mode": String,
+        }
+    }
 }
 
 /// Information about a breakpoint created in `setBreakpoints`, `setFunctionBreakpoints`, `setInstructionBreakpoints`, or `setDataBreakpoints` requests.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Breakpoint {
     /// The identifier for the breakpoint. It is needed if breakpoint events are used to update or remove breakpoints.
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub id: Option<u64>,
+    pub id: Option<i64>,
     /// If true, the breakpoint could be set (but not necessarily at the desired location).
     #[serde(rename = "verified")]
     pub verified: bool,
@@ -2737,22 +7129,87 @@ pub struct Breakpoint {
     #[serde(rename = "offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub offset: Option<u64>,
+    pub offset: Option<i64>,
     /// A machine-readable explanation of why a breakpoint may not be verified. If a breakpoint is verified or a specific reason is not known, the adapter should omit this property. Possible values include:
     ///
     /// - `pending`: Indicates a breakpoint might be verified in the future, but the adapter cannot verify it in the current state.
-    ///  - `failed`: Indicates a breakpoint was not able to be verified, and the adapter does not believe it can be verified without intervention.
+    /// - `failed`: Indicates a breakpoint was not able to be verified, and the adapter does not believe it can be verified without intervention.
     #[serde(rename = "reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub reason: Option<BreakpointReason>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Breakpoint {
+        required {
+            verified: bool,
+        }
+        optional {
+            id: i64,
+            message: String,
+            source: Source,
+            line: u64,
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+            instruction_reference: String,
+            offset: i64,
+            reason: BreakpointReason,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    Breakpoint {
+        required {
+            "verified": bool,
+        }
+        optional {
+            "id": u64,
+            "message": String,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "instructionReference": String,
+            "offset": i64,
+            "reason": BreakpointReason,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Breakpoint {
+        required {
+            "verified": bool,
+        }
+        optional {
+            "id": u64,
+            "message": String,
+            "source": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "instructionReference": String,
+            "offset": i64,
+            "reason": BreakpointReason,
+        }
+    }
 }
 
 /// A machine-readable explanation of why a breakpoint may not be verified. If a breakpoint is verified or a specific reason is not known, the adapter should omit this property. Possible values include:
 ///
 /// - `pending`: Indicates a breakpoint might be verified in the future, but the adapter cannot verify it in the current state.
-///  - `failed`: Indicates a breakpoint was not able to be verified, and the adapter does not believe it can be verified without intervention.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+/// - `failed`: Indicates a breakpoint was not able to be verified, and the adapter does not believe it can be verified without intervention.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum BreakpointReason {
     #[serde(rename = "pending")]
     Pending,
@@ -2760,8 +7217,13 @@ pub enum BreakpointReason {
     Failed,
 }
 
+crate::strict::impl_strict! { BreakpointReason }
+
+crate::lenient::impl_lenient! { BreakpointReason }
+
 /// The granularity of one 'step' in the stepping requests `next`, `stepIn`, `stepOut`, and `stepBack`.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum SteppingGranularity {
     /// The step should allow the program to run until the current statement has finished executing.
     /// The meaning of a statement is determined by the adapter and it may be considered equivalent to a line.
@@ -2776,12 +7238,17 @@ pub enum SteppingGranularity {
     Instruction,
 }
 
+crate::strict::impl_strict! { SteppingGranularity }
+
+crate::lenient::impl_lenient! { SteppingGranularity }
+
 /// A `StepInTarget` can be used in the `stepIn` request and determines into which single target the `stepIn` request should step.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StepInTarget {
     /// Unique identifier for a step-in target.
     #[serde(rename = "id")]
-    pub id: u64,
+    pub id: i64,
     /// The name of the step-in target (shown in the UI).
     #[serde(rename = "label")]
     pub label: String,
@@ -2805,15 +7272,65 @@ pub struct StepInTarget {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub end_column: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    StepInTarget {
+        required {
+            id: i64,
+            label: String,
+        }
+        optional {
+            line: u64,
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StepInTarget {
+        required {
+            "id": u64,
+            "label": String,
+        }
+        optional {
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StepInTarget {
+        required {
+            "id": u64,
+            "label": String,
+        }
+        optional {
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+        }
+    }
 }
 
 /// A `GotoTarget` describes a code location that can be used as a target in the `goto` request.
 /// The possible goto targets can be determined via the `gotoTargets` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct GotoTarget {
     /// Unique identifier for a goto target. This is used in the `goto` request.
     #[serde(rename = "id")]
-    pub id: u64,
+    pub id: i64,
     /// The name of the goto target (shown in the UI).
     #[serde(rename = "label")]
     pub label: String,
@@ -2840,10 +7357,63 @@ pub struct GotoTarget {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub instruction_pointer_reference: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    GotoTarget {
+        required {
+            id: i64,
+            label: String,
+            line: u64,
+        }
+        optional {
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+            instruction_pointer_reference: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    GotoTarget {
+        required {
+            "id": u64,
+            "label": String,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "instructionPointerReference": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    GotoTarget {
+        required {
+            "id": u64,
+            "label": String,
+            "line": u64,
+        }
+        optional {
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "instructionPointerReference": String,
+        }
+    }
 }
 
 /// `CompletionItems` are the suggestions returned from the `completions` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct CompletionItem {
     /// The label of this completion item. By default this is also the text that is inserted when selecting this completion.
     #[serde(rename = "label")]
@@ -2888,10 +7458,69 @@ pub struct CompletionItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub selection_length: Option<u64>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    CompletionItem {
+        required {
+            label: String,
+        }
+        optional {
+            text: String,
+            sort_text: String,
+            detail: String,
+            type_: CompletionItemType,
+            start: u64,
+            length: u64,
+            selection_start: u64,
+            selection_length: u64,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    CompletionItem {
+        required {
+            "label": String,
+        }
+        optional {
+            "text": String,
+            "sortText": String,
+            "detail": String,
+            "type": CompletionItemType,
+            "start": u64,
+            "length": u64,
+            "selectionStart": u64,
+            "selectionLength": u64,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    CompletionItem {
+        required {
+            "label": String,
+        }
+        optional {
+            "text": String,
+            "sortText": String,
+            "detail": String,
+            "type": CompletionItemType,
+            "start": u64,
+            "length": u64,
+            "selectionStart": u64,
+            "selectionLength": u64,
+        }
+    }
 }
 
 /// Some predefined types for the CompletionItem. Please note that not all clients have specific icons for all of them.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum CompletionItemType {
     #[serde(rename = "method")]
     Method,
@@ -2933,8 +7562,13 @@ pub enum CompletionItemType {
     Customcolor,
 }
 
+crate::strict::impl_strict! { CompletionItemType }
+
+crate::lenient::impl_lenient! { CompletionItemType }
+
 /// Names of checksum algorithms that may be supported by a debug adapter.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ChecksumAlgorithm {
     #[serde(rename = "MD5")]
     Md5,
@@ -2946,8 +7580,13 @@ pub enum ChecksumAlgorithm {
     Timestamp,
 }
 
+crate::strict::impl_strict! { ChecksumAlgorithm }
+
+crate::lenient::impl_lenient! { ChecksumAlgorithm }
+
 /// The checksum of an item calculated by the specified algorithm.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct Checksum {
     /// The algorithm used to calculate this checksum.
     #[serde(rename = "algorithm")]
@@ -2955,20 +7594,93 @@ pub struct Checksum {
     /// Value of the checksum, encoded as a hexadecimal value.
     #[serde(rename = "checksum")]
     pub checksum: String,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    Checksum {
+        required {
+            algorithm: ChecksumAlgorithm,
+            checksum: String,
+        }
+        optional {}
+    }
+}
+
+crate::strict::impl_strict! {
+    Checksum {
+        required {
+            "algorithm": ChecksumAlgorithm,
+            "checksum": String,
+        }
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! {
+    Checksum {
+        required {
+            "algorithm": ChecksumAlgorithm,
+            "checksum": String,
+        }
+        optional {}
+    }
 }
 
 /// Provides formatting information for a value.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ValueFormat {
     /// Display the value in hex.
     #[serde(rename = "hex")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub hex: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::merge::impl_merge! {
+    ValueFormat {
+        hex: "hex",
+    }
+}
+
+crate::builder::impl_builder! {
+    ValueFormat {
+        required {}
+        optional {
+            hex: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ValueFormat {
+        required {}
+        optional {
+            "hex": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ValueFormat {
+        required {}
+        optional {
+            "hex": bool,
+        }
+    }
 }
 
 /// Provides formatting information for a stack frame.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct StackFrameFormat {
     /// Display the value in hex.
     #[serde(rename = "hex")]
@@ -3010,10 +7722,76 @@ pub struct StackFrameFormat {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub include_all: Option<bool>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::merge::impl_merge! {
+    StackFrameFormat {
+        hex: "hex",
+        parameters: "parameters",
+        parameter_types: "parameterTypes",
+        parameter_names: "parameterNames",
+        parameter_values: "parameterValues",
+        line: "line",
+        module: "module",
+        include_all: "includeAll",
+    }
+}
+
+crate::builder::impl_builder! {
+    StackFrameFormat {
+        required {}
+        optional {
+            hex: bool,
+            parameters: bool,
+            parameter_types: bool,
+            parameter_names: bool,
+            parameter_values: bool,
+            line: bool,
+            module: bool,
+            include_all: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    StackFrameFormat {
+        required {}
+        optional {
+            "hex": bool,
+            "parameters": bool,
+            "parameterTypes": bool,
+            "parameterNames": bool,
+            "parameterValues": bool,
+            "line": bool,
+            "module": bool,
+            "includeAll": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    StackFrameFormat {
+        required {}
+        optional {
+            "hex": bool,
+            "parameters": bool,
+            "parameterTypes": bool,
+            "parameterNames": bool,
+            "parameterValues": bool,
+            "line": bool,
+            "module": bool,
+            "includeAll": bool,
+        }
+    }
 }
 
 /// An `ExceptionFilterOptions` is used to specify an exception filter together with a condition for the `setExceptionBreakpoints` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionFilterOptions {
     /// ID of an exception filter returned by the `exceptionBreakpointFilters` capability.
     #[serde(rename = "filterId")]
@@ -3029,10 +7807,51 @@ pub struct ExceptionFilterOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub mode: Option<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionFilterOptions {
+        required {
+            filter_id: String,
+        }
+        optional {
+            condition: String,
+            mode: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionFilterOptions {
+        required {
+            "filterId": String,
+        }
+        optional {
+            "condition": String,
+            "mode": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionFilterOptions {
+        required {
+            "filterId": String,
+        }
+        optional {
+            "condition": String,
+            "mode": String,
+        }
+    }
 }
 
 /// An `ExceptionOptions` assigns configuration options to a set of exceptions.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionOptions {
     /// A path that selects a single or multiple exceptions in a tree. If `path` is missing, the whole tree is selected.
     /// By convention the first segment of the path is a category that is used to group exceptions in the UI.
@@ -3043,6 +7862,43 @@ pub struct ExceptionOptions {
     /// Condition when a thrown exception should result in a break.
     #[serde(rename = "breakMode")]
     pub break_mode: ExceptionBreakMode,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionOptions {
+        required {
+            break_mode: ExceptionBreakMode,
+        }
+        optional {
+            path: Vec<ExceptionPathSegment>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionOptions {
+        required {
+            "breakMode": ExceptionBreakMode,
+        }
+        optional {
+            "path": Vec<ExceptionPathSegment>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionOptions {
+        required {
+            "breakMode": ExceptionBreakMode,
+        }
+        optional {
+            "path": Vec<ExceptionPathSegment>,
+        }
+    }
 }
 
 /// This enumeration defines all possible conditions when a thrown exception should result in a break.
@@ -3050,7 +7906,8 @@ pub struct ExceptionOptions {
 /// always: always breaks,
 /// unhandled: breaks when exception unhandled,
 /// userUnhandled: breaks if the exception is not handled by user code.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum ExceptionBreakMode {
     #[serde(rename = "never")]
     Never,
@@ -3062,9 +7919,14 @@ pub enum ExceptionBreakMode {
     UserUnhandled,
 }
 
+crate::strict::impl_strict! { ExceptionBreakMode }
+
+crate::lenient::impl_lenient! { ExceptionBreakMode }
+
 /// An `ExceptionPathSegment` represents a segment in a path that is used to match leafs or nodes in a tree of exceptions.
 /// If a segment consists of more than one name, it matches the names provided if `negate` is false or missing, or it matches anything except the names provided if `negate` is true.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionPathSegment {
     /// If false or missing this segment matches the names provided, otherwise it matches anything except the names provided.
     #[serde(rename = "negate")]
@@ -3074,10 +7936,48 @@ pub struct ExceptionPathSegment {
     /// Depending on the value of `negate` the names that should match or not match.
     #[serde(rename = "names")]
     pub names: Vec<String>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionPathSegment {
+        required {
+            names: Vec<String>,
+        }
+        optional {
+            negate: bool,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionPathSegment {
+        required {
+            "names": Vec<String>,
+        }
+        optional {
+            "negate": bool,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionPathSegment {
+        required {
+            "names": Vec<String>,
+        }
+        optional {
+            "negate": bool,
+        }
+    }
 }
 
 /// Detailed information about an exception that has occurred.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ExceptionDetails {
     /// Message contained in the exception.
     #[serde(rename = "message")]
@@ -3109,10 +8009,57 @@ pub struct ExceptionDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub inner_exception: Option<Vec<ExceptionDetails>>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    ExceptionDetails {
+        required {}
+        optional {
+            message: String,
+            type_name: String,
+            full_type_name: String,
+            evaluate_name: String,
+            stack_trace: String,
+            inner_exception: Vec<ExceptionDetails>,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    ExceptionDetails {
+        required {}
+        optional {
+            "message": String,
+            "typeName": String,
+            "fullTypeName": String,
+            "evaluateName": String,
+            "stackTrace": String,
+            "innerException": Vec<ExceptionDetails>,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    ExceptionDetails {
+        required {}
+        optional {
+            "message": String,
+            "typeName": String,
+            "fullTypeName": String,
+            "evaluateName": String,
+            "stackTrace": String,
+            "innerException": Vec<ExceptionDetails>,
+        }
+    }
 }
 
 /// Represents a single disassembled instruction.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct DisassembledInstruction {
     /// The address of the instruction. Treated as a hex value if prefixed with `0x`, or as a decimal value otherwise.
     #[serde(rename = "address")]
@@ -3164,43 +8111,119 @@ pub struct DisassembledInstruction {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub presentation_hint: Option<DisassembledInstructionPresentationHint>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    DisassembledInstruction {
+        required {
+            address: String,
+            instruction: String,
+        }
+        optional {
+            instruction_bytes: String,
+            symbol: String,
+            location: Source,
+            line: u64,
+            column: u64,
+            end_line: u64,
+            end_column: u64,
+            presentation_hint: DisassembledInstructionPresentationHint,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    DisassembledInstruction {
+        required {
+            "address": String,
+            "instruction": String,
+        }
+        optional {
+            "instructionBytes": String,
+            "symbol": String,
+            "location": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "presentationHint": DisassembledInstructionPresentationHint,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    DisassembledInstruction {
+        required {
+            "address": String,
+            "instruction": String,
+        }
+        optional {
+            "instructionBytes": String,
+            "symbol": String,
+            "location": Source,
+            "line": u64,
+            "column": u64,
+            "endLine": u64,
+            "endColumn": u64,
+            "presentationHint": DisassembledInstructionPresentationHint,
+        }
+    }
 }
 
 /// A hint for how to present the instruction in the UI.
 ///
 /// A value of `invalid` may be used to indicate this instruction is 'filler' and cannot be reached by the program. For example, unreadable memory addresses may be presented is 'invalid.'
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone)]
 pub enum DisassembledInstructionPresentationHint {
-    #[serde(rename = "normal")]
     Normal,
-    #[serde(rename = "invalid")]
     Invalid,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// A hint for how to present the instruction in the UI.
+    ///
+    /// A value of `invalid` may be used to indicate this instruction is 'filler' and cannot be reached by the program. For example, unreadable memory addresses may be presented is 'invalid.'
+    DisassembledInstructionPresentationHint {
+        Normal: "normal",
+        Invalid: "invalid",
+    }
 }
 
 /// Logical areas that can be invalidated by the `invalidated` event.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum InvalidatedAreas {
     /// All previously fetched data has become invalid and needs to be refetched.
-    #[serde(rename = "all")]
     All,
     /// Previously fetched stack related data has become invalid and needs to be refetched.
-    #[serde(rename = "stacks")]
     Stacks,
     /// Previously fetched thread related data has become invalid and needs to be refetched.
-    #[serde(rename = "threads")]
     Threads,
     /// Previously fetched variable data has become invalid and needs to be refetched.
-    #[serde(rename = "variables")]
     Variables,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// Logical areas that can be invalidated by the `invalidated` event.
+    InvalidatedAreas {
+        All: "all",
+        Stacks: "stacks",
+        Threads: "threads",
+        Variables: "variables",
+    }
 }
 
 /// A `BreakpointMode` is provided as a option when setting breakpoints on sources or instructions.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct BreakpointMode {
     /// The internal ID of the mode. This value is passed to the `setBreakpoints` request.
     #[serde(rename = "mode")]
@@ -3216,29 +8239,79 @@ pub struct BreakpointMode {
     /// Describes one or more type of breakpoint this mode applies to.
     #[serde(rename = "appliesTo")]
     pub applies_to: Vec<BreakpointModeApplicability>,
+    /// Properties not defined by the protocol, such as adapter-specific extensions.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+crate::builder::impl_builder! {
+    BreakpointMode {
+        required {
+            mode: String,
+            label: String,
+            applies_to: Vec<BreakpointModeApplicability>,
+        }
+        optional {
+            description: String,
+        }
+    }
+}
+
+crate::strict::impl_strict! {
+    BreakpointMode {
+        required {
+            "mode": String,
+            "label": String,
+            "appliesTo": Vec<BreakpointModeApplicability>,
+        }
+        optional {
+            "description": String,
+        }
+    }
+}
+
+crate::lenient::impl_lenient! {
+    BreakpointMode {
+        required {
+            "mode": String,
+            "label": String,
+            "appliesTo": Vec<BreakpointModeApplicability>,
+        }
+        optional {
+            "description": String,
+        }
+    }
 }
 
 /// Describes one or more type of breakpoint a `BreakpointMode` applies to. This is a non-exhaustive enumeration and may expand as future breakpoint types are added.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum BreakpointModeApplicability {
     /// In `SourceBreakpoint`s
-    #[serde(rename = "source")]
     Source,
     /// In exception breakpoints applied in the `ExceptionFilterOptions`
-    #[serde(rename = "exception")]
     Exception,
     /// In data breakpoints requested in the `DataBreakpointInfo` request
-    #[serde(rename = "data")]
     Data,
     /// In `InstructionBreakpoint`s
-    #[serde(rename = "instruction")]
     Instruction,
-    #[serde(other)]
-    Unknown,
+    /// A value not defined by the protocol. Equal to the known variant, if any.
+    Other(String),
+}
+
+crate::string_enum::impl_string_enum! {
+    /// Describes one or more type of breakpoint a `BreakpointMode` applies to. This is a non-exhaustive enumeration and may expand as future breakpoint types are added.
+    BreakpointModeApplicability {
+        Source: "source",
+        Exception: "exception",
+        Data: "data",
+        Instruction: "instruction",
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum ModuleId {
     Number(u32),
@@ -3246,18 +8319,21 @@ pub enum ModuleId {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct AttachRequestArguments {
     pub raw: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct LaunchRequestArguments {
     pub raw: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct RestartArguments {
     pub raw: serde_json::Value,
@@ -3265,4 +8341,161 @@ pub struct RestartArguments {
 
 /// Arguments for `Threads` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct ThreadsArgument {}
+
+crate::strict::impl_strict! { ModuleId }
+
+crate::strict::impl_strict! { AttachRequestArguments }
+
+crate::strict::impl_strict! { LaunchRequestArguments }
+
+crate::strict::impl_strict! { RestartArguments }
+
+crate::strict::impl_strict! {
+    ThreadsArgument {
+        required {}
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! { ModuleId }
+
+crate::lenient::impl_lenient! { AttachRequestArguments }
+
+crate::lenient::impl_lenient! { LaunchRequestArguments }
+
+crate::lenient::impl_lenient! { RestartArguments }
+
+crate::lenient::impl_lenient! {
+    ThreadsArgument {
+        required {}
+        optional {}
+    }
+}
+
+/// Adds the schemas of all protocol types to the definitions of `generator`.
+#[cfg(feature = "schemars")]
+pub(crate) fn add_schemas(generator: &mut schemars::SchemaGenerator) {
+    generator.subschema_for::<ErrorResponse>();
+    generator.subschema_for::<CancelArguments>();
+    generator.subschema_for::<StoppedEvent>();
+    generator.subschema_for::<ContinuedEvent>();
+    generator.subschema_for::<ExitedEvent>();
+    generator.subschema_for::<TerminatedEvent>();
+    generator.subschema_for::<ThreadEvent>();
+    generator.subschema_for::<OutputEvent>();
+    generator.subschema_for::<BreakpointEvent>();
+    generator.subschema_for::<ModuleEvent>();
+    generator.subschema_for::<LoadedSourceEvent>();
+    generator.subschema_for::<ProcessEvent>();
+    generator.subschema_for::<CapabilitiesEvent>();
+    generator.subschema_for::<ProgressStartEvent>();
+    generator.subschema_for::<ProgressUpdateEvent>();
+    generator.subschema_for::<ProgressEndEvent>();
+    generator.subschema_for::<InvalidatedEvent>();
+    generator.subschema_for::<MemoryEvent>();
+    generator.subschema_for::<RunInTerminalRequestArguments>();
+    generator.subschema_for::<RunInTerminalResponse>();
+    generator.subschema_for::<StartDebuggingRequestArguments>();
+    generator.subschema_for::<InitializeRequestArguments>();
+    generator.subschema_for::<ConfigurationDoneArguments>();
+    generator.subschema_for::<DisconnectArguments>();
+    generator.subschema_for::<TerminateArguments>();
+    generator.subschema_for::<BreakpointLocationsArguments>();
+    generator.subschema_for::<BreakpointLocationsResponse>();
+    generator.subschema_for::<SetBreakpointsArguments>();
+    generator.subschema_for::<SetBreakpointsResponse>();
+    generator.subschema_for::<SetFunctionBreakpointsArguments>();
+    generator.subschema_for::<SetFunctionBreakpointsResponse>();
+    generator.subschema_for::<SetExceptionBreakpointsArguments>();
+    generator.subschema_for::<SetExceptionBreakpointsResponse>();
+    generator.subschema_for::<DataBreakpointInfoArguments>();
+    generator.subschema_for::<DataBreakpointInfoResponse>();
+    generator.subschema_for::<SetDataBreakpointsArguments>();
+    generator.subschema_for::<SetDataBreakpointsResponse>();
+    generator.subschema_for::<SetInstructionBreakpointsArguments>();
+    generator.subschema_for::<SetInstructionBreakpointsResponse>();
+    generator.subschema_for::<ContinueArguments>();
+    generator.subschema_for::<ContinueResponse>();
+    generator.subschema_for::<NextArguments>();
+    generator.subschema_for::<StepInArguments>();
+    generator.subschema_for::<StepOutArguments>();
+    generator.subschema_for::<StepBackArguments>();
+    generator.subschema_for::<ReverseContinueArguments>();
+    generator.subschema_for::<RestartFrameArguments>();
+    generator.subschema_for::<GotoArguments>();
+    generator.subschema_for::<PauseArguments>();
+    generator.subschema_for::<StackTraceArguments>();
+    generator.subschema_for::<StackTraceResponse>();
+    generator.subschema_for::<ScopesArguments>();
+    generator.subschema_for::<ScopesResponse>();
+    generator.subschema_for::<VariablesArguments>();
+    generator.subschema_for::<VariablesResponse>();
+    generator.subschema_for::<SetVariableArguments>();
+    generator.subschema_for::<SetVariableResponse>();
+    generator.subschema_for::<SourceArguments>();
+    generator.subschema_for::<SourceResponse>();
+    generator.subschema_for::<ThreadsResponse>();
+    generator.subschema_for::<TerminateThreadsArguments>();
+    generator.subschema_for::<ModulesArguments>();
+    generator.subschema_for::<ModulesResponse>();
+    generator.subschema_for::<LoadedSourcesArguments>();
+    generator.subschema_for::<LoadedSourcesResponse>();
+    generator.subschema_for::<EvaluateArguments>();
+    generator.subschema_for::<EvaluateResponse>();
+    generator.subschema_for::<SetExpressionArguments>();
+    generator.subschema_for::<SetExpressionResponse>();
+    generator.subschema_for::<StepInTargetsArguments>();
+    generator.subschema_for::<StepInTargetsResponse>();
+    generator.subschema_for::<GotoTargetsArguments>();
+    generator.subschema_for::<GotoTargetsResponse>();
+    generator.subschema_for::<CompletionsArguments>();
+    generator.subschema_for::<CompletionsResponse>();
+    generator.subschema_for::<ExceptionInfoArguments>();
+    generator.subschema_for::<ExceptionInfoResponse>();
+    generator.subschema_for::<ReadMemoryArguments>();
+    generator.subschema_for::<ReadMemoryResponse>();
+    generator.subschema_for::<WriteMemoryArguments>();
+    generator.subschema_for::<WriteMemoryResponse>();
+    generator.subschema_for::<DisassembleArguments>();
+    generator.subschema_for::<DisassembleResponse>();
+    generator.subschema_for::<LocationsArguments>();
+    generator.subschema_for::<LocationsResponse>();
+    generator.subschema_for::<Capabilities>();
+    generator.subschema_for::<ExceptionBreakpointsFilter>();
+    generator.subschema_for::<Message>();
+    generator.subschema_for::<Module>();
+    generator.subschema_for::<ColumnDescriptor>();
+    generator.subschema_for::<Thread>();
+    generator.subschema_for::<Source>();
+    generator.subschema_for::<StackFrame>();
+    generator.subschema_for::<Scope>();
+    generator.subschema_for::<Variable>();
+    generator.subschema_for::<VariablePresentationHint>();
+    generator.subschema_for::<BreakpointLocation>();
+    generator.subschema_for::<SourceBreakpoint>();
+    generator.subschema_for::<FunctionBreakpoint>();
+    generator.subschema_for::<DataBreakpointAccessType>();
+    generator.subschema_for::<DataBreakpoint>();
+    generator.subschema_for::<InstructionBreakpoint>();
+    generator.subschema_for::<Breakpoint>();
+    generator.subschema_for::<SteppingGranularity>();
+    generator.subschema_for::<StepInTarget>();
+    generator.subschema_for::<GotoTarget>();
+    generator.subschema_for::<CompletionItem>();
+    generator.subschema_for::<CompletionItemType>();
+    generator.subschema_for::<ChecksumAlgorithm>();
+    generator.subschema_for::<Checksum>();
+    generator.subschema_for::<ValueFormat>();
+    generator.subschema_for::<StackFrameFormat>();
+    generator.subschema_for::<ExceptionFilterOptions>();
+    generator.subschema_for::<ExceptionOptions>();
+    generator.subschema_for::<ExceptionBreakMode>();
+    generator.subschema_for::<ExceptionPathSegment>();
+    generator.subschema_for::<ExceptionDetails>();
+    generator.subschema_for::<DisassembledInstruction>();
+    generator.subschema_for::<InvalidatedAreas>();
+    generator.subschema_for::<BreakpointMode>();
+    generator.subschema_for::<BreakpointModeApplicability>();
+}
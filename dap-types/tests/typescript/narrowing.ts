@@ -0,0 +1,44 @@
+// Checked by `tsc --noEmit --strict` in CI: comparing `event` must narrow `Events` to the event
+// with that name, and messages must be narrowed by `type`.
+import type {
+    Capabilities,
+    EventName,
+    Events,
+    OtherEvent,
+    OutputEvent,
+    ProtocolMessage,
+    StoppedEvent,
+} from "../../dap-types";
+
+function stopped(event: Events): StoppedEvent | undefined {
+    if (event.event === "stopped") {
+        return event.body;
+    }
+    return undefined;
+}
+
+function capabilities(event: Events): Capabilities | null | undefined {
+    switch (event.event) {
+        case "initialized":
+            return event.body;
+        default:
+            return undefined;
+    }
+}
+
+function output(message: ProtocolMessage): OutputEvent | undefined {
+    if (message.type === "event" && message.event === "output") {
+        return message.body;
+    }
+    return undefined;
+}
+
+function isKnown(event: Events | OtherEvent, names: Set<string>): event is Events {
+    return names.has(event.event);
+}
+
+const name: EventName = "memory";
+// @ts-expect-error: not an event defined by the protocol.
+const unknownName: EventName = "custom";
+
+export { stopped, capabilities, output, isKnown, name, unknownName };
@@ -3,15 +3,19 @@ use std::{collections::HashSet, path::PathBuf};
 use indexmap::IndexMap;
 use serde_json::{json, Map, Value};
 
+mod typescript;
+
 fn main() {
     let schema = load_schema();
     let protocol_types = generate_protocol_types(&schema);
     let types = write_types(&protocol_types);
     let requests = write_requests(&protocol_types);
     let events = write_events(&protocol_types);
-    write_file("types.rs", &types);
-    write_file("requests.rs", &requests);
-    write_file("events.rs", &events);
+    let typescript = typescript::write_typescript(&protocol_types);
+    write_file("src/types.rs", &types);
+    write_file("src/requests.rs", &requests);
+    write_file("src/events.rs", &events);
+    write_file(TYPESCRIPT_FILE, &typescript);
     println!("{types}");
 }
 
@@ -27,11 +31,16 @@ fn generated_files_are_up_to_date() {
     let types = write_types(&protocol_types);
     let requests = write_requests(&protocol_types);
     let events = write_events(&protocol_types);
-    check_file("types.rs", &types);
-    check_file("requests.rs", &requests);
-    check_file("events.rs", &events);
+    let typescript = typescript::write_typescript(&protocol_types);
+    check_file("src/types.rs", &types);
+    check_file("src/requests.rs", &requests);
+    check_file("src/events.rs", &events);
+    check_file(TYPESCRIPT_FILE, &typescript);
 }
 
+/// TypeScript definitions of the types, for clients that handle the messages in TypeScript.
+const TYPESCRIPT_FILE: &str = "dap-types.d.ts";
+
 fn write_file(file: &str, contents: &str) {
     let contents = with_disclaimer(contents);
     std::fs::write(dst_path(file), contents).unwrap();
@@ -48,7 +57,6 @@ fn dst_path(file: &str) -> PathBuf {
         .unwrap();
     let mut path = workspace_dir.to_owned();
     path.push("dap-types");
-    path.push("src");
     path.push(file);
     path
 }
@@ -63,6 +71,101 @@ fn load_schema() -> Value {
     serde_json::from_str(&contents).unwrap()
 }
 
+/// Integer fields that the protocol documents as possibly negative, generated as i64 instead of
+/// u64. These are the memory offsets of `MemoryEvent`, `ReadMemoryArguments`,
+/// `WriteMemoryArguments` and `WriteMemoryResponse`, and the offsets of `DisassembleArguments`,
+/// `InstructionBreakpoint` and `Breakpoint`.
+const SIGNED_FIELDS: &[&str] = &["offset", "instructionOffset"];
+
+/// Id and reference fields that some adapters send negative values for, although the protocol
+/// only allows non-negative ones. Delve, for example, sends a thread id of -1 when a panic is hit
+/// (https://github.com/go-delve/delve/pull/4027). They are generated as i64 so that such messages
+/// deserialize, and are still checked as unsigned by strict checking. `requestId` is left out,
+/// since it is the `seq` of a request sent by the client.
+const NEGATIVE_ID_FIELDS: &[&str] = &[
+    "id",
+    "threadId",
+    "threadIds",
+    "hitBreakpointIds",
+    "frameId",
+    "stackFrameId",
+    "targetId",
+    "variablesReference",
+    "sourceReference",
+    "locationReference",
+    "declarationLocationReference",
+    "valueLocationReference",
+];
+
+/// Option bags that are updated by values setting only some of their fields, and implement
+/// `Merge`: capabilities updated by `capabilities` events, and formatting options applied over
+/// defaults.
+const MERGE_TYPES: &[&str] = &["Capabilities", "ValueFormat", "StackFrameFormat"];
+
+/// The adapter capability that must be true for a client to send each request, or `None` for
+/// requests that are always allowed. Every request in the schema must be listed. The reverse
+/// requests `runInTerminal` and `startDebugging` depend on client capabilities, which are not
+/// in `Capabilities`, so they are listed as always allowed.
+const REQUEST_CAPABILITIES: &[(&str, Option<&str>)] = &[
+    ("cancel", Some("supportsCancelRequest")),
+    ("runInTerminal", None),
+    ("startDebugging", None),
+    ("initialize", None),
+    (
+        "configurationDone",
+        Some("supportsConfigurationDoneRequest"),
+    ),
+    ("launch", None),
+    ("attach", None),
+    ("restart", Some("supportsRestartRequest")),
+    ("disconnect", None),
+    ("terminate", Some("supportsTerminateRequest")),
+    (
+        "breakpointLocations",
+        Some("supportsBreakpointLocationsRequest"),
+    ),
+    ("setBreakpoints", None),
+    (
+        "setFunctionBreakpoints",
+        Some("supportsFunctionBreakpoints"),
+    ),
+    ("setExceptionBreakpoints", None),
+    ("dataBreakpointInfo", Some("supportsDataBreakpoints")),
+    ("setDataBreakpoints", Some("supportsDataBreakpoints")),
+    (
+        "setInstructionBreakpoints",
+        Some("supportsInstructionBreakpoints"),
+    ),
+    ("continue", None),
+    ("next", None),
+    ("stepIn", None),
+    ("stepOut", None),
+    ("stepBack", Some("supportsStepBack")),
+    ("reverseContinue", Some("supportsStepBack")),
+    ("restartFrame", Some("supportsRestartFrame")),
+    ("goto", Some("supportsGotoTargetsRequest")),
+    ("pause", None),
+    ("stackTrace", None),
+    ("scopes", None),
+    ("variables", None),
+    ("setVariable", Some("supportsSetVariable")),
+    ("source", None),
+    ("threads", None),
+    ("terminateThreads", Some("supportsTerminateThreadsRequest")),
+    ("modules", Some("supportsModulesRequest")),
+    ("loadedSources", Some("supportsLoadedSourcesRequest")),
+    ("evaluate", None),
+    ("setExpression", Some("supportsSetExpression")),
+    ("stepInTargets", Some("supportsStepInTargetsRequest")),
+    ("gotoTargets", Some("supportsGotoTargetsRequest")),
+    ("completions", Some("supportsCompletionsRequest")),
+    ("exceptionInfo", Some("supportsExceptionInfoRequest")),
+    ("readMemory", Some("supportsReadMemoryRequest")),
+    ("writeMemory", Some("supportsWriteMemoryRequest")),
+    ("disassemble", Some("supportsDisassembleRequest")),
+    ("locations", None),
+];
+
 const BLACKLISTED_TYPES: &[&str] = &[
     "ProtocolMessage",
     "Request",
@@ -79,6 +182,21 @@ fn write_requests(types: &[ProtocolType]) -> String {
     writer.line("use std::fmt::Debug;");
     writer.finished_object();
     writer.code(REQUEST_TRAIT);
+    let capabilities = types
+        .iter()
+        .find(|t| t.name == "Capabilities")
+        .unwrap()
+        .ty
+        .as_object();
+    for (command, capability) in REQUEST_CAPABILITIES {
+        if let Some(capability) = capability {
+            assert!(
+                capabilities.find_field(capability).is_some(),
+                "capability {capability} of {command} is not a property of Capabilities"
+            );
+        }
+    }
+    let mut commands = Vec::new();
     for ty in types {
         let Type::Object(o) = &ty.ty else {
             continue;
@@ -93,7 +211,8 @@ fn write_requests(types: &[ProtocolType]) -> String {
             continue;
         }
         let command = o.find_field("command").unwrap().ty.as_enum().single_value();
-        let arguments = match &o.find_field("arguments").unwrap().ty {
+        let arguments_field = o.find_field("arguments").unwrap();
+        let arguments = match &arguments_field.ty {
             Type::Any if command == "threads" => "crate::ThreadsArgument".to_owned(),
             Type::Any => "()".to_owned(),
             Type::Basic(args) => format!("crate::{args}"),
@@ -103,12 +222,20 @@ fn write_requests(types: &[ProtocolType]) -> String {
         let response = format!("{request}Response");
         let response_ty = types.iter().find(|t| t.name == response).unwrap();
         let ro = response_ty.ty.as_object();
-        let response_body = match &ro.find_field("body").unwrap().ty {
+        let body_field = ro.find_field("body").unwrap();
+        let response_body = match &body_field.ty {
             Type::Any => "()".to_owned(),
             Type::Basic(body) => format!("crate::{body}"),
             Type::Object(_) => format!("crate::{response}"),
             _ => panic!("bad response body for {}", ty.name),
         };
+        commands.push(Dispatch {
+            name: command.to_owned(),
+            arguments: dispatch_type(&arguments_field.ty, &arguments),
+            body: dispatch_type(&body_field.ty, &response_body),
+            arguments_required: arguments_field.required,
+            body_required: body_field.required,
+        });
         writer.doc(o.doc.as_ref().unwrap());
         writer.line(format!("pub enum {request} {{}}"));
         writer.finished_object();
@@ -116,18 +243,79 @@ fn write_requests(types: &[ProtocolType]) -> String {
         writer.indented(format!("const COMMAND: &'static str = {command:?};"));
         writer.indented(format!("type Arguments = {arguments};"));
         writer.indented(format!("type Response = {response_body};"));
+        let (_, capability) = REQUEST_CAPABILITIES
+            .iter()
+            .find(|(c, _)| *c == command)
+            .unwrap_or_else(|| panic!("request {command} is missing from REQUEST_CAPABILITIES"));
+        if let Some(capability) = capability {
+            let field = to_snake_case(capability);
+            writer.indented(format!(
+                "const CAPABILITY: Option<&'static str> = Some({capability:?});"
+            ));
+            writer.indented("fn is_supported(capabilities: &crate::Capabilities) -> bool {");
+            writer.indented(format!("    capabilities.{field} == Some(true)"));
+            writer.indented("}");
+        }
         writer.line("}");
         writer.finished_object();
     }
+    for (command, _) in REQUEST_CAPABILITIES {
+        assert!(
+            commands.iter().any(|c| c.name == *command),
+            "REQUEST_CAPABILITIES lists {command}, which is not a request"
+        );
+    }
+    writer.code(COMMAND_VISITOR);
+    writer.line("/// Calls `visitor` with the types of the request `command`, or returns `None` if the protocol");
+    writer.line("/// does not define it.");
+    writer.line("pub(crate) fn visit_command<V: CommandVisitor>(command: &str, visitor: V) -> Option<V::Output> {");
+    writer.indented("Some(match command {");
+    for c in &commands {
+        let arm = format!(
+            "    {:?} => visitor.visit::<{}, {}>({}, {}),",
+            c.name, c.arguments, c.body, c.arguments_required, c.body_required
+        );
+        // rustfmt does not wrap over-long match arms, so they are wrapped here.
+        if arm.len() + 4 <= 100 {
+            writer.indented(arm);
+        } else {
+            writer.indented(format!("    {:?} => visitor.visit::<", c.name));
+            writer.indented(format!("        {},", c.arguments));
+            writer.indented(format!("        {},", c.body));
+            writer.indented(format!(
+                "    >({}, {}),",
+                c.arguments_required, c.body_required
+            ));
+        }
+    }
+    writer.indented("    _ => return None,");
+    writer.indented("})");
+    writer.line("}");
     writer.output
 }
 
-fn write_events(types: &[ProtocolType]) -> String {
-    let mut writer = Writer::default();
-    writer.line("use serde::{de::DeserializeOwned, Serialize};");
-    writer.line("use std::fmt::Debug;");
-    writer.finished_object();
-    writer.code(EVENT_TRAIT);
+/// A request in the dispatch on its command, with the types its arguments and response body
+/// are checked as.
+struct Dispatch {
+    name: String,
+    arguments: String,
+    body: String,
+    arguments_required: bool,
+    body_required: bool,
+}
+
+/// The type a dispatched payload is checked and coerced as. Payloads the protocol leaves
+/// unspecified are checked as any JSON value, rather than as the type they are generated as.
+fn dispatch_type(spec: &Type, generated: &str) -> String {
+    match spec {
+        Type::Any => "serde_json::Value".to_owned(),
+        _ => generated.to_owned(),
+    }
+}
+
+/// The event types defined by the protocol, with the name of each event.
+fn event_types(types: &[ProtocolType]) -> Vec<(&ProtocolType, &str)> {
+    let mut events = Vec::new();
     for ty in types {
         let Type::Object(o) = &ty.ty else {
             continue;
@@ -142,11 +330,25 @@ fn write_events(types: &[ProtocolType]) -> String {
             continue;
         }
         let name = o.find_field("event").unwrap().ty.as_enum().single_value();
+        events.push((ty, name));
+    }
+    events
+}
 
-        let body = match &o.find_field("body").unwrap().ty {
+fn write_events(types: &[ProtocolType]) -> String {
+    let mut writer = Writer::default();
+    writer.line("use serde::{de::DeserializeOwned, Serialize};");
+    writer.line("use std::fmt::Debug;");
+    writer.finished_object();
+    writer.code(EVENT_TRAIT);
+    let mut events = Vec::new();
+    for (ty, name) in event_types(types) {
+        let o = ty.ty.as_object();
+        let body_field = o.find_field("body").unwrap();
+        let body = match &body_field.ty {
             Type::Any => {
                 if name == "initialized" {
-                    format!("Option<crate::Capabilities>")
+                    "Option<crate::Capabilities>".to_owned()
                 } else {
                     "()".to_owned()
                 }
@@ -155,6 +357,11 @@ fn write_events(types: &[ProtocolType]) -> String {
             Type::Object(_) => format!("crate::{}", ty.name),
             _ => panic!("bad body type for {}", ty.name),
         };
+        events.push((
+            name,
+            dispatch_type(&body_field.ty, &body),
+            body_field.required,
+        ));
         let event = ty.name.strip_suffix("Event").unwrap();
         writer.doc(o.doc.as_ref().unwrap());
         writer.line(format!("pub enum {event} {{}}"));
@@ -165,48 +372,73 @@ fn write_events(types: &[ProtocolType]) -> String {
         writer.line("}");
         writer.finished_object();
     }
+    writer.code(EVENT_VISITOR);
+    writer.line("/// Calls `visitor` with the body type of the event `event`, or returns `None` if the protocol");
+    writer.line("/// does not define it.");
+    writer.line("pub(crate) fn visit_event<V: EventVisitor>(event: &str, visitor: V) -> Option<V::Output> {");
+    writer.indented("Some(match event {");
+    for (name, body, required) in &events {
+        writer.indented(format!(
+            "    {name:?} => visitor.visit::<{body}>({required}),"
+        ));
+    }
+    writer.indented("    _ => return None,");
+    writer.indented("})");
+    writer.line("}");
     writer.output
 }
 
-fn write_types(types: &[ProtocolType]) -> String {
-    let mut writer = Writer::default();
-    writer.line("use schemars::JsonSchema;");
-    writer.line("use serde::{Deserialize, Serialize};");
-    writer.finished_object();
+/// The types that are written to `types.rs`. Requests are left out, and responses and events are
+/// represented by their body.
+fn type_definitions(types: &[ProtocolType]) -> Vec<ProtocolType> {
+    let mut definitions = Vec::new();
     for ty in types {
         if ty.name.ends_with("Request") {
             continue;
         }
-        println!("writing type {}", ty.name);
-        if ty.name.ends_with("Response") {
+        if ty.name.ends_with("Response") || ty.name.ends_with("Event") {
             let body = &ty.ty.as_object().find_field("body").unwrap().ty;
             match body {
                 Type::Any => continue,
                 Type::Object(o) => {
                     let mut o = o.clone();
                     o.doc = o.doc.or(ty.ty.doc());
-                    o.write(&ty.name, &mut writer);
-                }
-                Type::Basic(_) => continue,
-                _ => panic!(),
-            }
-        } else if ty.name.ends_with("Event") {
-            let body = &ty.ty.as_object().find_field("body").unwrap().ty;
-            match body {
-                Type::Any => continue,
-                Type::Object(o) => {
-                    let mut o = o.clone();
-                    o.doc = o.doc.or(ty.ty.doc());
-                    o.write(&ty.name, &mut writer);
+                    definitions.push(ProtocolType {
+                        name: ty.name.clone(),
+                        ty: Type::Object(o),
+                    });
                 }
                 Type::Basic(_) => continue,
                 _ => panic!(),
             }
         } else {
-            ty.write(&mut writer);
+            definitions.push(ty.clone());
         }
     }
+    definitions
+}
+
+fn write_types(types: &[ProtocolType]) -> String {
+    let mut writer = Writer::default();
+    writer.line("#[cfg(feature = \"schemars\")]");
+    writer.line("use schemars::JsonSchema;");
+    writer.line("use serde::{Deserialize, Serialize};");
+    writer.finished_object();
+    let definitions = type_definitions(types);
+    for ty in &definitions {
+        println!("writing type {}", ty.name);
+        ty.write(&mut writer);
+    }
     writer.code(CUSTOM_TYPES);
+    writer.finished_object();
+    writer.line("/// Adds the schemas of all protocol types to the definitions of `generator`.");
+    writer.line("#[cfg(feature = \"schemars\")]");
+    writer.line("pub(crate) fn add_schemas(generator: &mut schemars::SchemaGenerator) {");
+    for ty in &definitions {
+        writer.indented(format!("generator.subschema_for::<{}>();", ty.name));
+    }
+    writer.line("}");
+    writer.finished_object();
     writer.output
 }
 
@@ -286,25 +518,27 @@ fn translate_object(defs: &Map<String, Value>, def: &Value) -> Object {
 }
 
 fn generate_field(defs: &Map<String, Value>, name: &str, def: &Value, required: bool) -> Field {
-    let ty = if name == "hitBreakpointIds" || name == "threadIds" {
-        // Note: i64 is used instead of u64 here because delve returns -1 when a panic is hit.
-        // Everywhere else we assume (though the spec doesn't specify) that breakpoint ids are u64s.
-        // https://github.com/go-delve/delve/pull/4027
-        assert_eq!(def.get("type"), Some(&json!("array")));
-        assert_eq!(def.get("items"), Some(&json!({ "type": "integer" })));
-        Type::Vec(Box::new("i64".into()))
-    } else if name == "threadId" {
+    let spec_ty = if SIGNED_FIELDS.contains(&name) {
         assert_eq!(def.get("type"), Some(&json!("integer")));
         Type::Basic("i64".into())
     } else {
         translate_type(defs, def)
     };
+    let ty = match &spec_ty {
+        Type::Basic(ty) if ty == "u64" && NEGATIVE_ID_FIELDS.contains(&name) => "i64".into(),
+        Type::Vec(item) if NEGATIVE_ID_FIELDS.contains(&name) => {
+            assert!(matches!(&**item, Type::Basic(ty) if ty == "u64"));
+            Type::Vec(Box::new("i64".into()))
+        }
+        _ => spec_ty.clone(),
+    };
     Field {
         doc: def
             .get("description")
             .map(|x| x.as_str().unwrap().to_owned()),
         name: name.to_owned(),
         ty,
+        spec_ty,
         required,
     }
 }
@@ -448,6 +682,23 @@ fn words(raw: &str) -> Vec<String> {
     result
 }
 
+/// Splits a schema description into doc comment lines. Schema descriptions often continue with
+/// plain text right after a list, which rustdoc would fold into the last list item, so a blank
+/// line is inserted there.
+fn doc_lines(doc: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut in_list = false;
+    for line in doc.lines().map(str::trim_start) {
+        let is_item = line.starts_with("- ");
+        if in_list && !is_item && !line.is_empty() {
+            lines.push("");
+        }
+        in_list = is_item;
+        lines.push(line);
+    }
+    lines
+}
+
 #[derive(Default)]
 struct Writer {
     output: String,
@@ -457,7 +708,7 @@ struct Writer {
 impl Writer {
     fn check_finish(&mut self) {
         if self.finished_object {
-            self.output.push_str("\n");
+            self.output.push('\n');
             self.finished_object = false;
         }
     }
@@ -465,14 +716,14 @@ impl Writer {
     fn line(&mut self, line: impl AsRef<str>) {
         self.check_finish();
         self.output.push_str(line.as_ref().trim_end());
-        self.output.push_str("\n");
+        self.output.push('\n');
     }
 
     fn indented(&mut self, line: impl AsRef<str>) {
         self.check_finish();
         self.output.push_str("    ");
         self.output.push_str(line.as_ref().trim_end());
-        self.output.push_str("\n");
+        self.output.push('\n');
     }
 
     fn finished_object(&mut self) {
@@ -480,20 +731,20 @@ impl Writer {
     }
 
     fn doc(&mut self, doc: impl AsRef<str>) {
-        for line in doc.as_ref().lines() {
+        for line in doc_lines(doc.as_ref()) {
             self.line(format!("/// {line}"));
         }
     }
 
     fn indented_doc(&mut self, doc: impl AsRef<str>) {
-        for line in doc.as_ref().lines() {
+        for line in doc_lines(doc.as_ref()) {
             self.indented(format!("/// {line}"));
         }
     }
 
     fn code(&mut self, code: &str) {
         for line in code.lines() {
-            if line == "" {
+            if line.is_empty() {
                 self.finished_object();
                 continue;
             }
@@ -507,6 +758,7 @@ impl Writer {
     }
 }
 
+#[derive(Clone)]
 struct ProtocolType {
     name: String,
     ty: Type,
@@ -554,6 +806,29 @@ impl Enum {
         assert!(self.exhaustive);
         &self.variants[0]
     }
+
+    /// Whether values not listed by the protocol are accepted, and kept in an `Other` variant.
+    fn is_open(&self, name: &str) -> bool {
+        !self.exhaustive || name.ends_with("PresentationHint")
+    }
+
+    /// The known values, including ones that adapters send although the protocol does not list
+    /// them.
+    fn values(&self, name: &str) -> Vec<String> {
+        let mut values = self.variants.clone();
+        values.extend(self.values_not_in_protocol(name));
+        values
+    }
+
+    /// Values that adapters send although the protocol does not list them. They get variants of
+    /// their own, but strict checking reports them.
+    fn values_not_in_protocol(&self, name: &str) -> Vec<String> {
+        if name.ends_with("StackFramePresentationHint") {
+            vec!["deemphasize".to_owned()]
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 impl From<&str> for Type {
@@ -581,6 +856,9 @@ struct Field {
     doc: Option<String>,
     name: String,
     ty: Type,
+    /// The type as defined by the protocol, before any workarounds for adapters that do not
+    /// follow it. Used for strict checking.
+    spec_ty: Type,
     required: bool,
 }
 
@@ -600,92 +878,244 @@ impl ProtocolType {
 impl Object {
     fn write(&self, name: &str, dst: &mut Writer) {
         if let Some(doc) = &self.doc {
-            dst.doc(&doc);
+            dst.doc(doc);
         }
-        dst.line("#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]");
-        let mut pending = Vec::new();
-        if self.fields.is_empty() {
-            dst.line(format!("pub struct {} {{}}", name));
+        let all_optional = self.fields.iter().all(|f| !f.required);
+        if all_optional {
+            dst.line(
+                "#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]",
+            );
         } else {
-            dst.line(format!("pub struct {} {{", name));
-            for field in &self.fields {
-                let inline_name = format!("{}{}", name, to_pascal_case(&field.name));
-                let mut ty = field.ty.stringify(inline_name, &mut pending);
-                if let Some(doc) = &field.doc {
-                    dst.indented_doc(doc);
-                }
-                dst.indented(format!("#[serde(rename = \"{}\")]", field.name));
-
-                // GDB doesn't always send a threads name so we default to an empty string
-                // Debugpy doesn't send a cwd in a run in terminal request if the client doesn't provide it in the launch request
-                // An empty string indicates that we should use the project's cwd
-                if (name == "Thread" && field.name == "name")
-                    || (name == "RunInTerminalRequestArguments" && field.name == "cwd")
-                {
-                    dst.indented("#[serde(default)]");
-                }
-                if name == "Thread" && field.name == "id" {
-                    ty = "i64".to_string();
-                }
+            dst.line("#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]");
+        }
+        dst.line(SCHEMA_DERIVE);
+        let mut pending = Vec::new();
+        let mut builder_fields = Vec::new();
+        let mut strict_fields = Vec::new();
+        let mut lenient_fields = Vec::new();
+        dst.line(format!("pub struct {} {{", name));
+        for field in &self.fields {
+            let inline_name = format!("{}{}", name, to_pascal_case(&field.name));
+            let spec_ty = field
+                .spec_ty
+                .stringify(inline_name.clone(), &mut Vec::new());
+            strict_fields.push((field.name.clone(), spec_ty.clone(), field.required));
+            // Coerced as the protocol type, so that values that only deserialize because of a
+            // workaround, like negative ids, are reported.
+            lenient_fields.push((field.name.clone(), spec_ty, field.required));
+            let ty = field.ty.stringify(inline_name, &mut pending);
+            if let Some(doc) = &field.doc {
+                dst.indented_doc(doc);
+            }
+            dst.indented(format!("#[serde(rename = \"{}\")]", field.name));
 
-                let clean_name = to_snake_case(&field.name);
-                if field.required {
-                    dst.indented(format!("pub {}: {},", clean_name, ty));
-                } else {
-                    dst.indented("#[serde(skip_serializing_if = \"Option::is_none\")]");
-                    dst.indented("#[serde(default)]");
-                    dst.indented(format!("pub {}: Option<{}>,", clean_name, ty));
-                }
+            // GDB doesn't always send a threads name so we default to an empty string
+            // Debugpy doesn't send a cwd in a run in terminal request if the client doesn't provide it in the launch request
+            // An empty string indicates that we should use the project's cwd
+            if (name == "Thread" && field.name == "name")
+                || (name == "RunInTerminalRequestArguments" && field.name == "cwd")
+            {
+                dst.indented("#[serde(default)]");
+            }
+
+            if field.required && ty.starts_with("Option<") {
+                // A nullable property that must be present.
+                dst.indented("#[cfg_attr(feature = \"schemars\", schemars(required))]");
+            }
+
+            let clean_name = to_snake_case(&field.name);
+            builder_fields.push((clean_name.clone(), ty.clone(), field.required));
+            if field.required {
+                dst.indented(format!("pub {}: {},", clean_name, ty));
+            } else {
+                dst.indented("#[serde(skip_serializing_if = \"Option::is_none\")]");
+                dst.indented("#[serde(default)]");
+                dst.indented(format!("pub {}: Option<{}>,", clean_name, ty));
             }
-            dst.line("}");
         }
+        dst.indented(
+            "/// Properties not defined by the protocol, such as adapter-specific extensions.",
+        );
+        dst.indented("#[cfg(feature = \"extra-fields\")]");
+        dst.indented("#[serde(flatten)]");
+        dst.indented("pub extra: serde_json::Map<String, serde_json::Value>,");
+        dst.line("}");
         dst.finished_object();
+        if MERGE_TYPES.contains(&name) {
+            assert!(all_optional, "{name} has required fields");
+            self.write_merge(name, dst);
+        }
+        write_builder(name, &builder_fields, dst);
+        write_protocol_fields("crate::strict::impl_strict", name, &strict_fields, dst);
+        write_protocol_fields("crate::lenient::impl_lenient", name, &lenient_fields, dst);
         for p in pending {
             p.write(dst);
         }
     }
 
+    /// Implements `Merge` through the `impl_merge!` macro, which keeps the generated code short
+    /// and leaves its formatting to us rather than to rustfmt.
+    fn write_merge(&self, name: &str, dst: &mut Writer) {
+        dst.line("crate::merge::impl_merge! {");
+        dst.indented(format!("{name} {{"));
+        for field in &self.fields {
+            dst.indented(format!(
+                "    {}: {:?},",
+                to_snake_case(&field.name),
+                field.name
+            ));
+        }
+        dst.indented("}");
+        dst.line("}");
+        dst.finished_object();
+    }
+
     fn find_field(&self, name: &str) -> Option<&Field> {
         self.fields.iter().find(|f| f.name == name)
     }
 }
 
+/// Implements `new` and setters through the `impl_builder!` macro. `fields` holds the Rust name,
+/// type and whether the field is required, for each field.
+fn write_builder(name: &str, fields: &[(String, String, bool)], dst: &mut Writer) {
+    dst.line("crate::builder::impl_builder! {");
+    dst.indented(format!("{name} {{"));
+    for (group, required) in [("required", true), ("optional", false)] {
+        let group_fields = fields
+            .iter()
+            .filter(|(_, _, r)| *r == required)
+            .collect::<Vec<_>>();
+        if group_fields.is_empty() {
+            dst.indented(format!("    {group} {{}}"));
+            continue;
+        }
+        dst.indented(format!("    {group} {{"));
+        for (field, ty, _) in group_fields {
+            dst.indented(format!("        {field}: {ty},"));
+        }
+        dst.indented("    }");
+    }
+    dst.indented("}");
+    dst.line("}");
+    dst.finished_object();
+}
+
+/// Invokes a macro like `impl_strict!` that works on the JSON representation of a struct.
+/// `fields` holds the protocol name, type and whether the field is required, for each field.
+fn write_protocol_fields(
+    macro_path: &str,
+    name: &str,
+    fields: &[(String, String, bool)],
+    dst: &mut Writer,
+) {
+    dst.line(format!("{macro_path}! {{"));
+    dst.indented(format!("{name} {{"));
+    for (group, required) in [("required", true), ("optional", false)] {
+        let group_fields = fields
+            .iter()
+            .filter(|(_, _, r)| *r == required)
+            .collect::<Vec<_>>();
+        if group_fields.is_empty() {
+            dst.indented(format!("    {group} {{}}"));
+            continue;
+        }
+        dst.indented(format!("    {group} {{"));
+        for (field, ty, _) in group_fields {
+            dst.indented(format!("        {field:?}: {ty},"));
+        }
+        dst.indented("    }");
+    }
+    dst.indented("}");
+    dst.line("}");
+    dst.finished_object();
+}
+
 impl Enum {
     fn write(&self, name: &str, dst: &mut Writer) {
         if let Some(doc) = &self.doc {
             dst.doc(doc);
         }
-        if self.exhaustive {
-            dst.line("#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize, JsonSchema)]");
+        let open = self.is_open(name);
+        if !open {
+            dst.line("#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Deserialize, Serialize)]");
+            dst.line(SCHEMA_DERIVE);
         } else {
-            dst.line("#[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]");
+            // Equality and hashing are implemented by `impl_string_enum!`, by value.
+            dst.line("#[derive(Debug, Clone)]");
         }
         if !self.exhaustive {
             dst.line("#[non_exhaustive]");
         }
 
+        let variants = self
+            .values(name)
+            .into_iter()
+            .map(|value| (to_pascal_case(&value), value))
+            .collect::<Vec<_>>();
+        if let Some(desc) = &self.variant_descriptions {
+            assert!(desc.len() == self.variants.len());
+        }
         dst.line(format!("pub enum {} {{", name));
-        for (i, value) in self.variants.iter().enumerate() {
-            if let Some(desc) = &self.variant_descriptions {
-                assert!(desc.len() == self.variants.len());
-                dst.indented_doc(&desc[i]);
+        for (i, (variant, value)) in variants.iter().enumerate() {
+            if let Some(desc) = self.variant_descriptions.as_ref().and_then(|d| d.get(i)) {
+                dst.indented_doc(desc);
             }
-            dst.indented(format!("#[serde(rename = \"{value}\")]"));
-            dst.indented(format!("{},", to_pascal_case(value)));
-        }
-        if name.ends_with("StackFramePresentationHint") {
-            dst.indented(format!("#[serde(rename = \"deemphasize\")]"));
-            dst.indented("Deemphasize,");
+            if !open {
+                dst.indented(format!("#[serde(rename = \"{value}\")]"));
+            }
+            dst.indented(format!("{variant},"));
         }
-        if !self.exhaustive || name.ends_with("PresentationHint") {
-            dst.indented("#[serde(other)]");
-            dst.indented("Unknown,");
+        if open {
+            dst.indented_doc(
+                "A value not defined by the protocol. Equal to the known variant, if any.",
+            );
+            dst.indented("Other(String),");
         }
         dst.line("}");
         dst.finished_object();
+        if open {
+            let (known, not_in_protocol) = variants.split_at(self.variants.len());
+            write_string_enum(name, self.doc.as_deref(), known, not_in_protocol, dst);
+        } else {
+            dst.line(format!("crate::strict::impl_strict! {{ {name} }}"));
+            dst.finished_object();
+            dst.line(format!("crate::lenient::impl_lenient! {{ {name} }}"));
+            dst.finished_object();
+        }
     }
 }
 
+/// Implements serialization of an open enum through the `impl_string_enum!` macro. `variants`
+/// holds the Rust name and protocol value of each variant defined by the protocol, and
+/// `not_in_protocol` those of the variants added for adapters that do not follow it. The
+/// documentation is repeated for the schema, which can not be derived.
+fn write_string_enum(
+    name: &str,
+    doc: Option<&str>,
+    variants: &[(String, String)],
+    not_in_protocol: &[(String, String)],
+    dst: &mut Writer,
+) {
+    dst.line("crate::string_enum::impl_string_enum! {");
+    if let Some(doc) = doc {
+        dst.indented_doc(doc);
+    }
+    dst.indented(format!("{name} {{"));
+    for (variant, value) in variants {
+        dst.indented(format!("    {variant}: {value:?},"));
+    }
+    dst.indented("}");
+    if !not_in_protocol.is_empty() {
+        dst.indented("not_in_protocol {");
+        for (variant, value) in not_in_protocol {
+            dst.indented(format!("    {variant}: {value:?},"));
+        }
+        dst.indented("}");
+    }
+    dst.line("}");
+    dst.finished_object();
+}
+
 impl Type {
     fn stringify(&self, inline_name: String, pending: &mut Vec<PendingInline>) -> String {
         match self {
@@ -729,8 +1159,11 @@ impl PendingInline {
     }
 }
 
+const SCHEMA_DERIVE: &str = "#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]";
+
 const CUSTOM_TYPES: &str = "
 #[derive(PartialEq, Eq, Debug, Hash, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum ModuleId {
     Number(u32),
@@ -738,18 +1171,21 @@ pub enum ModuleId {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct AttachRequestArguments {
     pub raw: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct LaunchRequestArguments {
     pub raw: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Hash)]
+#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]
 #[serde(transparent)]
 pub struct RestartArguments {
     pub raw: serde_json::Value,
@@ -757,7 +1193,38 @@ pub struct RestartArguments {
 
 /// Arguments for `Threads` request.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = \"schemars\", derive(JsonSchema))]
 pub struct ThreadsArgument {}
+
+crate::strict::impl_strict! { ModuleId }
+
+crate::strict::impl_strict! { AttachRequestArguments }
+
+crate::strict::impl_strict! { LaunchRequestArguments }
+
+crate::strict::impl_strict! { RestartArguments }
+
+crate::strict::impl_strict! {
+    ThreadsArgument {
+        required {}
+        optional {}
+    }
+}
+
+crate::lenient::impl_lenient! { ModuleId }
+
+crate::lenient::impl_lenient! { AttachRequestArguments }
+
+crate::lenient::impl_lenient! { LaunchRequestArguments }
+
+crate::lenient::impl_lenient! { RestartArguments }
+
+crate::lenient::impl_lenient! {
+    ThreadsArgument {
+        required {}
+        optional {}
+    }
+}
 ";
 
 const REQUEST_TRAIT: &str = "
@@ -766,6 +1233,42 @@ pub trait Request {
     const COMMAND: &'static str;
     type Arguments: Debug + Clone + Serialize + DeserializeOwned + Send + Sync;
     type Response: Debug + Clone + Serialize + DeserializeOwned + Send + Sync;
+    /// The adapter capability that must be true for a client to send this request, if any.
+    const CAPABILITY: Option<&'static str> = None;
+
+    /// Returns whether the adapter supports this request, according to its capabilities.
+    fn is_supported(_capabilities: &crate::Capabilities) -> bool {
+        true
+    }
+}
+";
+
+const COMMAND_VISITOR: &str = "
+/// Receives the types of a request whose command is only known at runtime, to check or coerce
+/// its arguments and response body.
+pub(crate) trait CommandVisitor {
+    type Output;
+
+    /// Called with the types the request arguments and the success response body are checked
+    /// as, and whether the protocol requires them.
+    fn visit<A, B>(self, arguments_required: bool, body_required: bool) -> Self::Output
+    where
+        A: crate::strict::Strict + crate::lenient::Lenient,
+        B: crate::strict::Strict + crate::lenient::Lenient;
+}
+";
+
+const EVENT_VISITOR: &str = "
+/// Receives the body type of an event whose name is only known at runtime, to check or coerce
+/// its body.
+pub(crate) trait EventVisitor {
+    type Output;
+
+    /// Called with the type the event body is checked as, and whether the protocol requires it.
+    fn visit<B: crate::strict::Strict + crate::lenient::Lenient>(
+        self,
+        body_required: bool,
+    ) -> Self::Output;
 }
 ";
 
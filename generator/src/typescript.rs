@@ -0,0 +1,198 @@
+//! TypeScript definitions of the protocol types, matching their serde representation.
+
+use crate::{event_types, type_definitions, Enum, Object, ProtocolType, Type, Writer};
+
+pub fn write_typescript(types: &[ProtocolType]) -> String {
+    let mut writer = Writer::default();
+    writer.line("// Enums that adapters may extend accept any string; `(string & {})` keeps the");
+    writer.line("// values defined by the protocol available for completion.");
+    writer.line("//");
+    writer.line("// Properties kept by the `extra-fields` feature are not declared; values may");
+    writer.line("// have more properties than their type lists.");
+    writer.finished_object();
+    for ty in type_definitions(types) {
+        write_definition(&ty.name, &ty.ty, &mut writer);
+    }
+    writer.code(CUSTOM_TYPES);
+    writer.code(MESSAGES);
+    write_events(types, &mut writer);
+    writer.output
+}
+
+/// Writes the union of the events defined by the protocol, matching `messages::Events`. Events
+/// not defined by the protocol are left out of the union, so that comparing `event` narrows it.
+fn write_events(types: &[ProtocolType], dst: &mut Writer) {
+    dst.line("/** An event defined by the protocol, as in `dap_types::messages::Events`. */");
+    dst.line("export type Events =");
+    let events = event_types(types);
+    for (i, (ty, name)) in events.iter().enumerate() {
+        let body_field = ty.ty.as_object().find_field("body").unwrap();
+        let body = match &body_field.ty {
+            // `Events::Initialized` holds capabilities, although the protocol does not define
+            // the body of the event.
+            Type::Any if *name == "initialized" => "Capabilities".to_owned(),
+            Type::Any => "unknown".to_owned(),
+            Type::Basic(body) => body.clone(),
+            Type::Object(_) => ty.name.clone(),
+            _ => panic!("bad body type for {}", ty.name),
+        };
+        let body = if body_field.required {
+            format!("body: {body}")
+        } else {
+            format!("body?: {body} | null")
+        };
+        let end = if i + 1 == events.len() { ";" } else { "" };
+        dst.indented(format!("| {{ event: {name:?}; {body} }}{end}"));
+    }
+    dst.finished_object();
+    dst.code(OTHER_EVENT);
+}
+
+fn write_definition(name: &str, ty: &Type, dst: &mut Writer) {
+    match ty {
+        Type::Enum(e) => write_enum(name, e, dst),
+        Type::Object(o) => write_object(name, o, dst),
+        _ => panic!("unexpected definition {name}"),
+    }
+}
+
+fn write_object(name: &str, o: &Object, dst: &mut Writer) {
+    if let Some(doc) = &o.doc {
+        write_doc(doc, dst, false);
+    }
+    let mut pending = Vec::new();
+    if o.fields.is_empty() {
+        dst.line(format!("export type {name} = Record<string, never>;"));
+    } else {
+        dst.line(format!("export interface {name} {{"));
+        for field in &o.fields {
+            if let Some(doc) = &field.doc {
+                write_doc(doc, dst, true);
+            }
+            let inline_name = format!("{}{}", name, crate::to_pascal_case(&field.name));
+            let ty = stringify(&field.ty, inline_name, &mut pending);
+            let optional = if field.required { "" } else { "?" };
+            dst.indented(format!("{}{optional}: {ty};", field.name));
+        }
+        dst.line("}");
+    }
+    dst.finished_object();
+    for (name, ty) in pending {
+        write_definition(&name, &ty, dst);
+    }
+}
+
+fn write_enum(name: &str, e: &Enum, dst: &mut Writer) {
+    if let Some(doc) = &e.doc {
+        write_doc(doc, dst, false);
+    }
+    let mut values = e
+        .values(name)
+        .iter()
+        .map(|value| format!("{value:?}"))
+        .collect::<Vec<_>>();
+    if e.is_open(name) {
+        values.push("(string & {})".to_owned());
+    }
+    dst.line(format!("export type {name} ="));
+    for value in &values[..values.len() - 1] {
+        dst.indented(format!("| {value}"));
+    }
+    dst.indented(format!("| {};", values[values.len() - 1]));
+    dst.finished_object();
+}
+
+/// The TypeScript type of a field. Inline enums and objects are named like in the Rust types, and
+/// added to `pending` to be written after the type that uses them.
+fn stringify(ty: &Type, inline_name: String, pending: &mut Vec<(String, Type)>) -> String {
+    match ty {
+        Type::Any => "unknown".to_owned(),
+        Type::Basic(name) => match name.as_str() {
+            "u32" | "u64" | "i64" => "number".to_owned(),
+            "bool" => "boolean".to_owned(),
+            "String" => "string".to_owned(),
+            _ => name.clone(),
+        },
+        Type::Enum(_) | Type::Object(_) => {
+            pending.push((inline_name.clone(), ty.clone()));
+            inline_name
+        }
+        Type::Vec(item) => match stringify(item, inline_name, pending) {
+            item if item.contains(' ') => format!("({item})[]"),
+            item => format!("{item}[]"),
+        },
+        Type::Option(inner) => format!("{} | null", stringify(inner, inline_name, pending)),
+    }
+}
+
+fn write_doc(doc: &str, dst: &mut Writer, indented: bool) {
+    let mut lines = vec!["/**".to_owned()];
+    lines.extend(
+        crate::doc_lines(doc)
+            .into_iter()
+            .map(|line| format!(" * {}", line.replace("*/", "*\\/"))),
+    );
+    lines.push(" */".to_owned());
+    for line in lines {
+        if indented {
+            dst.indented(line);
+        } else {
+            dst.line(line);
+        }
+    }
+}
+
+const CUSTOM_TYPES: &str = "
+export type ModuleId = number | string;
+
+export type AttachRequestArguments = unknown;
+
+export type LaunchRequestArguments = unknown;
+
+export type RestartArguments = unknown;
+
+/** Arguments for `Threads` request. */
+export type ThreadsArgument = Record<string, never>;
+";
+
+/// The envelopes of `dap_types::messages`, except for `Events`, which is generated from the event
+/// types. `Message` is named `ProtocolMessage` here, since the protocol defines a `Message` type
+/// of its own.
+const MESSAGES: &str = r#"
+/** A message as represented by `dap_types::messages::Message`. */
+export type ProtocolMessage =
+    | ({ type: "event" } & Events)
+    | ({ type: "response" } & Response)
+    | ({ type: "request" } & Request);
+
+export interface Request {
+    seq: number;
+    command: string;
+    arguments?: unknown;
+}
+
+export interface Response {
+    seq: number;
+    request_seq: number;
+    success: boolean;
+    command: string;
+    body?: unknown;
+    message?: string;
+}
+
+"#;
+
+const OTHER_EVENT: &str = r#"
+/** The name of an event defined by the protocol. */
+export type EventName = Events["event"];
+
+/**
+ * An event not defined by the protocol, as represented by `dap_types::messages::Events::Other`.
+ * It is not part of `Events`, so that comparing `event` narrows `Events`. A message whose `event`
+ * is not an `EventName` can be treated as an `OtherEvent`.
+ */
+export interface OtherEvent {
+    event: string;
+    body: unknown;
+}
+"#;